@@ -0,0 +1,4 @@
+use criterion::{criterion_group, criterion_main};
+
+criterion_group!(benches, natural_sort::bench::bench_all);
+criterion_main!(benches);
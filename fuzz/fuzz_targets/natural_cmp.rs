@@ -0,0 +1,30 @@
+//! Checks that comparing never panics and stays antisymmetric on arbitrary input
+//!
+//! Run with `cargo +nightly fuzz run natural_cmp` from the repository root.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use natural_sort::{natural_cmp, natural_cmp_ci, natural_eq_signature, try_natural_cmp};
+
+fuzz_target!(|data: &[u8]| {
+    let split = data.iter().position(|b| *b == 0).unwrap_or(data.len());
+    let (a, b) = data.split_at(split);
+    let b = b.get(1..).unwrap_or_default();
+
+    let _ = try_natural_cmp(a, b);
+
+    let a = String::from_utf8_lossy(a);
+    let b = String::from_utf8_lossy(b);
+
+    let ord = natural_cmp(&a, &b);
+    assert_eq!(ord, natural_cmp(&b, &a).reverse());
+    assert_eq!(
+        ord.is_eq(),
+        natural_eq_signature(&a) == natural_eq_signature(&b)
+    );
+    assert_eq!(natural_cmp_ci(&a, &b), natural_cmp_ci(&b, &a).reverse());
+
+    let mut list = vec![a.as_ref(), b.as_ref()];
+    natural_sort::natural_sort(&mut list);
+});
@@ -0,0 +1,31 @@
+#ifndef NATURAL_SORT_H
+#define NATURAL_SORT_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/**
+ * Compares two NUL terminated strings in a natural way
+ *
+ * Returns a negative number, zero or a positive number like `strcmp`.
+ * Null pointers are treated as empty strings.
+ */
+int natural_cmp_cstr(const char *a, const char *b);
+
+/**
+ * Compares two UTF-8 buffers of known length in a natural way
+ *
+ * Returns a negative number, zero or a positive number like `memcmp`.
+ * The buffers don't need to be NUL terminated.
+ */
+int natural_cmp_utf8(const uint8_t *a, size_t a_len, const uint8_t *b, size_t b_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* NATURAL_SORT_H */
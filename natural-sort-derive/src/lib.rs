@@ -0,0 +1,94 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Index, Member};
+
+/// Derives `PartialEq`, `Eq`, `PartialOrd` and `Ord` using natural comparison of string fields
+///
+/// Fields marked with `#[natural]` are compared in declaration order and the first one
+/// that differs decides. Without any marked fields only the first field is compared.
+/// Every compared field must implement `AsRef<str>`, and unmarked fields are ignored.
+///
+/// Usually used through the `derive` feature of `natural-sort`, which re-exports it.
+#[proc_macro_derive(NaturalOrd, attributes(natural))]
+pub fn derive_natural_ord(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "NaturalOrd can only be derived for structs",
+            ))
+        }
+    };
+
+    let members = compared_members(fields)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::core::cmp::Ord for #name #ty_generics #where_clause {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                ::core::cmp::Ordering::Equal
+                    #(.then_with(|| ::natural_sort::natural_cmp(
+                        ::core::convert::AsRef::<str>::as_ref(&self.#members),
+                        ::core::convert::AsRef::<str>::as_ref(&other.#members),
+                    )))*
+            }
+        }
+
+        impl #impl_generics ::core::cmp::PartialOrd for #name #ty_generics #where_clause {
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        impl #impl_generics ::core::cmp::PartialEq for #name #ty_generics #where_clause {
+            fn eq(&self, other: &Self) -> bool {
+                ::core::cmp::Ord::cmp(self, other) == ::core::cmp::Ordering::Equal
+            }
+        }
+
+        impl #impl_generics ::core::cmp::Eq for #name #ty_generics #where_clause {}
+    })
+}
+
+fn compared_members(fields: &Fields) -> syn::Result<Vec<Member>> {
+    let members: Vec<Member> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(i)),
+        })
+        .collect();
+
+    let mut marked = vec![];
+    for (field, member) in fields.iter().zip(&members) {
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("natural")) {
+            attr.meta.require_path_only()?;
+            marked.push(member.clone());
+        }
+    }
+
+    if !marked.is_empty() {
+        return Ok(marked);
+    }
+
+    match members.into_iter().next() {
+        Some(first) => Ok(vec![first]),
+        None => Err(Error::new_spanned(
+            fields,
+            "NaturalOrd needs at least one field to compare",
+        )),
+    }
+}
@@ -0,0 +1,180 @@
+//! A port of the `alphanumeric-sort` crate's `compare_str`
+
+use std::cmp::Ordering;
+use std::str::Chars;
+
+/// A string being read, with room to put back one character
+struct Reader<'a> {
+    chars: Chars<'a>,
+    pending: Option<char>,
+}
+
+impl Reader<'_> {
+    fn next(&mut self) -> Option<char> {
+        self.pending.take().or_else(|| self.chars.next())
+    }
+
+    /// Consumes the following ASCII digits and returns how many there were
+    fn skip_digits(&mut self) -> usize {
+        let mut count = 0;
+        for c in self.chars.by_ref() {
+            if !c.is_ascii_digit() {
+                self.pending = Some(c);
+                break;
+            }
+            count += 1;
+        }
+        count
+    }
+
+    /// Skips leading zeros, returning the first other digit if the number has one
+    fn skip_zeros(&mut self, mut c: char, zeros: &mut isize, step: isize) -> Option<char> {
+        while c == '0' {
+            *zeros += step;
+            match self.chars.next() {
+                Some(next) if next.is_ascii_digit() => c = next,
+                Some(next) => {
+                    self.pending = Some(next);
+                    return None;
+                }
+                None => return None,
+            }
+        }
+        Some(c)
+    }
+}
+
+/// Compares like `alphanumeric_sort::compare_str`
+///
+/// Only ASCII digits form numbers and leading zeros only break ties, the
+/// string that had more of them at the first difference sorts last. Right
+/// after a number, characters above U+00FF sort before the others.
+pub(crate) fn alphanumeric(a: &str, b: &str) -> Ordering {
+    let mut a = Reader {
+        chars: a.chars(),
+        pending: None,
+    };
+    let mut b = Reader {
+        chars: b.chars(),
+        pending: None,
+    };
+
+    let mut last_is_number = false;
+    let mut pre_answer = Ordering::Equal;
+
+    loop {
+        let Some(ca) = a.next() else {
+            return match b.next() {
+                Some(_) => Ordering::Less,
+                None => pre_answer,
+            };
+        };
+        let Some(cb) = b.next() else {
+            return Ordering::Greater;
+        };
+
+        if !(ca.is_ascii_digit() && cb.is_ascii_digit()) {
+            match ca.cmp(&cb) {
+                Ordering::Equal => last_is_number = false,
+                ord => {
+                    let wide_once = (ca > '\u{ff}') ^ (cb > '\u{ff}');
+                    return match last_is_number && wide_once {
+                        true => ord.reverse(),
+                        false => ord,
+                    };
+                }
+            }
+            continue;
+        }
+
+        let mut zeros = 0;
+        let ordering = match (
+            a.skip_zeros(ca, &mut zeros, 1),
+            b.skip_zeros(cb, &mut zeros, -1),
+        ) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(ca), Some(cb)) => match compare_significant(&mut a, &mut b, ca, cb) {
+                Some(ord) => ord,
+                None => return Ordering::Less,
+            },
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+        last_is_number = true;
+        if pre_answer == Ordering::Equal {
+            pre_answer = zeros.cmp(&0);
+        }
+    }
+}
+
+/// Compares two numbers without their leading zeros, starting at `ca` and `cb`
+///
+/// Returns `None` where the original returns `Less` straight away, without
+/// looking at the rest of the strings.
+fn compare_significant(
+    a: &mut Reader,
+    b: &mut Reader,
+    mut ca: char,
+    mut cb: char,
+) -> Option<Ordering> {
+    loop {
+        if ca != cb {
+            let la = 1 + a.skip_digits();
+            let lb = 1 + b.skip_digits();
+            return Some(match la == lb {
+                true => ca.cmp(&cb),
+                false => la.cmp(&lb),
+            });
+        }
+
+        match a.chars.next() {
+            Some(c) if c.is_ascii_digit() => match b.chars.next() {
+                Some(cc) if cc.is_ascii_digit() => {
+                    ca = c;
+                    cb = cc;
+                }
+                _ => return Some(Ordering::Greater),
+            },
+            Some(c) => {
+                let n = b.skip_digits();
+                a.pending = Some(c);
+                return match n > 0 {
+                    true => None,
+                    false => Some(Ordering::Equal),
+                };
+            }
+            None => {
+                return match b.chars.next() {
+                    Some(_) => None,
+                    None => Some(Ordering::Equal),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_alphanumeric() {
+    let pairs = [
+        ("1點", "1-1點", Ordering::Less),
+        ("1a", "01", Ordering::Greater),
+        ("001", "01", Ordering::Greater),
+        ("0000", "000", Ordering::Greater),
+        ("0000", "001", Ordering::Less),
+        ("shot-2", "shot-10", Ordering::Less),
+        ("shot-01", "shot-1", Ordering::Greater),
+        ("shot-01", "shot-2", Ordering::Less),
+        ("1.02", "1.1", Ordering::Greater),
+        ("a١", "a1", Ordering::Greater),
+        ("", "", Ordering::Equal),
+    ];
+
+    for (a, b, ord) in pairs {
+        assert_eq!(alphanumeric(a, b), ord, "{:?} {:?}", a, b);
+        assert_eq!(alphanumeric(b, a), ord.reverse(), "{:?} {:?}", b, a);
+    }
+}
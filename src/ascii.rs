@@ -0,0 +1,135 @@
+use std::cmp::Ordering;
+
+/// Compares two string slices in a natural way, in a `const` context
+///
+/// Only ASCII digits count as numbers, so this agrees with
+/// [`natural_cmp`](crate::natural_cmp) for every string without other numeric
+/// characters. Anything else is compared byte by byte.
+///
+/// # Arguments
+///
+/// * `a` - The left hand side
+/// * `b` - The right hand side
+///
+/// # Examples
+/// ```
+/// use std::cmp::Ordering;
+/// use natural_sort::natural_cmp_ascii;
+/// const ORDER: Ordering = natural_cmp_ascii("item9", "item10");
+/// assert_eq!(ORDER, Ordering::Less);
+/// ```
+pub const fn natural_cmp_ascii(a: &str, b: &str) -> Ordering {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+    let (mut i, mut j) = (0, 0);
+
+    loop {
+        loop {
+            let a_done = i == a.len() || a[i].is_ascii_digit();
+            let b_done = j == b.len() || b[j].is_ascii_digit();
+            match (a_done, b_done) {
+                (true, true) => break,
+                (true, false) => return Ordering::Less,
+                (false, true) => return Ordering::Greater,
+                (false, false) if a[i] < b[j] => return Ordering::Less,
+                (false, false) if a[i] > b[j] => return Ordering::Greater,
+                (false, false) => {}
+            }
+            i += 1;
+            j += 1;
+        }
+
+        match (i < a.len(), j < b.len()) {
+            (false, false) => return Ordering::Equal,
+            (false, true) => return Ordering::Less,
+            (true, false) => return Ordering::Greater,
+            (true, true) => {}
+        }
+
+        while i < a.len() && a[i] == b'0' {
+            i += 1;
+        }
+        while j < b.len() && b[j] == b'0' {
+            j += 1;
+        }
+        let a_end = digits_end(a, i);
+        let b_end = digits_end(b, j);
+        if a_end - i != b_end - j {
+            return match a_end - i < b_end - j {
+                true => Ordering::Less,
+                false => Ordering::Greater,
+            };
+        }
+
+        while i < a_end {
+            if a[i] != b[j] {
+                return match a[i] < b[j] {
+                    true => Ordering::Less,
+                    false => Ordering::Greater,
+                };
+            }
+            i += 1;
+            j += 1;
+        }
+    }
+}
+
+/// Returns whether a slice is in natural order, in a `const` context
+///
+/// Meant for static tables: a `const` assertion turns an out of order entry
+/// into a compile error. Numbers are read as by
+/// [`natural_cmp_ascii`].
+///
+/// # Arguments
+///
+/// * `vals` - The values to check
+///
+/// # Examples
+/// ```
+/// use natural_sort::is_natural_sorted_ascii;
+/// const MENU: [&str; 3] = ["Track 1", "Track 2", "Track 10"];
+/// const _: () = assert!(is_natural_sorted_ascii(&MENU));
+/// ```
+pub const fn is_natural_sorted_ascii(vals: &[&str]) -> bool {
+    let mut index = 1;
+    while index < vals.len() {
+        if let Ordering::Greater = natural_cmp_ascii(vals[index - 1], vals[index]) {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Returns the index just past the ASCII digits starting at `start`
+const fn digits_end(s: &[u8], mut start: usize) -> usize {
+    while start < s.len() && s[start].is_ascii_digit() {
+        start += 1;
+    }
+    start
+}
+
+#[test]
+fn test_natural_cmp_ascii() {
+    let inputs = [
+        "", "a", "ab", "a1", "a01", "a10", "a2b", "a2", "z10a", "z10", "007", "0", "00", "x0y",
+        "v1.2.10", "v1.2.9", "ä1", "a 1", "10a", "9z", "A1", "_1",
+    ];
+
+    for a in inputs {
+        for b in inputs {
+            assert_eq!(
+                natural_cmp_ascii(a, b),
+                crate::natural_cmp(a, b),
+                "{:?} {:?}",
+                a,
+                b
+            );
+        }
+    }
+
+    const { assert!(is_natural_sorted_ascii(&["a", "a2", "a10", "b"])) };
+    assert!(!is_natural_sorted_ascii(&["a10", "a2"]));
+    assert!(is_natural_sorted_ascii(&[]));
+    assert!(is_natural_sorted_ascii(&["a01", "a1"]));
+}
@@ -0,0 +1,241 @@
+//! Dataset generators and Criterion entry points, enabled with the `bench-util` feature
+//!
+//! The generators are deterministic for a given seed, so numbers taken on
+//! different machines or before and after a change compare the same inputs.
+//!
+//! ```no_run
+//! use criterion::{criterion_group, criterion_main, Criterion};
+//! use natural_sort::bench;
+//!
+//! fn my_workload(c: &mut Criterion) {
+//!     let names: Vec<String> = std::fs::read_to_string("names.txt")
+//!         .unwrap()
+//!         .lines()
+//!         .map(String::from)
+//!         .collect();
+//!     bench::bench_sorts(c, "names", &names);
+//! }
+//!
+//! criterion_group!(benches, bench::bench_all, my_workload);
+//! criterion_main!(benches);
+//! ```
+
+use criterion::{BatchSize, Criterion};
+
+use crate::{
+    natural_cmp_ci, natural_key_bytes, natural_sort, natural_sort_radix, Mode, NaturalKeyCache,
+    NaturalOptions, NaturalSorter, Strength,
+};
+
+/// The number of values `bench_all` generates per dataset
+pub const DEFAULT_LEN: usize = 10_000;
+
+/// A small splitmix64 generator, good enough to shuffle test data
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.below(items.len() as u64) as usize]
+    }
+}
+
+/// Generates release file names like `app-2.10.3-rc1.tar.gz`
+///
+/// # Arguments
+///
+/// * `len` - How many names to generate
+/// * `seed` - Seeds the generator
+pub fn versioned_filenames(len: usize, seed: u64) -> Vec<String> {
+    let mut rng = Rng(seed);
+    let names = ["app", "libfoo", "server", "tool", "plugin"];
+    let suffixes = ["", "-rc1", "-rc2", "-beta", "-beta10"];
+    let extensions = [".tar.gz", ".zip", ".deb"];
+
+    (0..len)
+        .map(|_| {
+            format!(
+                "{}-{}.{}.{}{}{}",
+                rng.pick(&names),
+                rng.below(4),
+                rng.below(30),
+                rng.below(200),
+                rng.pick(&suffixes),
+                rng.pick(&extensions)
+            )
+        })
+        .collect()
+}
+
+/// Generates counters like `frame_000042.png`, mixing padded and unpadded numbers
+///
+/// # Arguments
+///
+/// * `len` - How many names to generate
+/// * `seed` - Seeds the generator
+pub fn zero_padded_counters(len: usize, seed: u64) -> Vec<String> {
+    let mut rng = Rng(seed);
+    let prefixes = ["frame_", "IMG_", "shot", "track "];
+
+    (0..len)
+        .map(|_| {
+            let prefix = rng.pick(&prefixes);
+            let n = rng.below(1_000_000);
+            match rng.below(3) {
+                0 => format!("{}{}.png", prefix, n),
+                1 => format!("{}{:06}.png", prefix, n),
+                _ => format!("{}{:09}.png", prefix, n),
+            }
+        })
+        .collect()
+}
+
+/// Generates names that embed UUIDs, like `upload-1f0c3a9e-...-report7.pdf`
+///
+/// # Arguments
+///
+/// * `len` - How many names to generate
+/// * `seed` - Seeds the generator
+pub fn uuid_mixed_names(len: usize, seed: u64) -> Vec<String> {
+    let mut rng = Rng(seed);
+    let prefixes = ["upload", "cache", "session"];
+
+    (0..len)
+        .map(|_| {
+            let (a, b) = (rng.next(), rng.next());
+            format!(
+                "{}-{:08x}-{:04x}-{:04x}-{:04x}-{:012x}-report{}.pdf",
+                rng.pick(&prefixes),
+                a >> 32,
+                (a >> 16) & 0xffff,
+                a & 0xffff,
+                b >> 48,
+                b & 0xffff_ffff_ffff,
+                rng.below(100)
+            )
+        })
+        .collect()
+}
+
+/// Benchmarks every sorting entry point of the crate on one dataset
+///
+/// Each benchmark sorts a fresh copy of `vals`, named `<group>/<entry point>`.
+/// Every [`Mode`] is covered through a [`NaturalSorter`], and the cached key
+/// paths both with a cold and a warm [`NaturalKeyCache`].
+///
+/// # Arguments
+///
+/// * `c` - The Criterion instance
+/// * `group` - Names the benchmark group
+/// * `vals` - The values to sort
+pub fn bench_sorts(c: &mut Criterion, group: &str, vals: &[String]) {
+    let mut group = c.benchmark_group(group);
+
+    group.bench_function("natural_sort", |b| {
+        b.iter_batched(
+            || vals.iter().map(String::as_str).collect::<Vec<_>>(),
+            |mut vals| natural_sort(&mut vals),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("natural_sort_radix", |b| {
+        b.iter_batched(
+            || vals.to_vec(),
+            |mut vals| natural_sort_radix(&mut vals),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("natural_cmp_ci", |b| {
+        b.iter_batched(
+            || vals.to_vec(),
+            |mut vals| vals.sort_by(|x, y| natural_cmp_ci(x, y)),
+            BatchSize::SmallInput,
+        )
+    });
+
+    let mut sorter = NaturalSorter::with_options(NaturalOptions::new().strength(Strength::Primary));
+    group.bench_function("sorter_primary", |b| {
+        b.iter_batched(
+            || vals.to_vec(),
+            |mut vals| sorter.sort(&mut vals),
+            BatchSize::SmallInput,
+        )
+    });
+
+    for (name, mode) in [
+        ("sorter_strverscmp", Mode::Strverscmp),
+        ("sorter_natord", Mode::Natord),
+        ("sorter_alphanumeric", Mode::Alphanumeric),
+    ] {
+        let mut sorter = NaturalSorter::with_options(NaturalOptions::new().mode(mode));
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || vals.to_vec(),
+                |mut vals| sorter.sort(&mut vals),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+
+    group.bench_function("natural_key_bytes", |b| {
+        b.iter_batched(
+            || vals.to_vec(),
+            |mut vals| vals.sort_by_cached_key(|val| natural_key_bytes(val)),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("key_cache_cold", |b| {
+        b.iter_batched(
+            || (vals.to_vec(), NaturalKeyCache::new(vals.len())),
+            |(mut vals, mut cache)| cache.sort(&mut vals),
+            BatchSize::SmallInput,
+        )
+    });
+
+    let mut cache = NaturalKeyCache::new(vals.len());
+    cache.sort(&mut vals.to_vec());
+    group.bench_function("key_cache_warm", |b| {
+        b.iter_batched(
+            || vals.to_vec(),
+            |mut vals| cache.sort(&mut vals),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+/// Benchmarks every sorting entry point on each of the generated datasets
+///
+/// # Arguments
+///
+/// * `c` - The Criterion instance
+pub fn bench_all(c: &mut Criterion) {
+    bench_sorts(c, "versioned", &versioned_filenames(DEFAULT_LEN, 1));
+    bench_sorts(c, "counters", &zero_padded_counters(DEFAULT_LEN, 2));
+    bench_sorts(c, "uuids", &uuid_mixed_names(DEFAULT_LEN, 3));
+}
+
+#[test]
+fn test_generators() {
+    for generate in [versioned_filenames, zero_padded_counters, uuid_mixed_names] {
+        let vals = generate(100, 7);
+        assert_eq!(vals.len(), 100);
+        assert_eq!(vals, generate(100, 7));
+        assert_ne!(vals, generate(100, 8));
+    }
+
+    let name = &uuid_mixed_names(1, 0)[0];
+    assert_eq!(name.split('-').count(), 7);
+}
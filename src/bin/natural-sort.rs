@@ -0,0 +1,243 @@
+//! Sorts lines or NUL separated records from files or stdin in natural order
+
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{self, BufReader, Read, Write};
+use std::path::Path;
+use std::process::ExitCode;
+
+use natural_sort::{natural_cmp, natural_sort_records, normalize_numbers};
+
+const USAGE: &str = "\
+Usage: natural-sort [OPTION]... [FILE]...
+Writes the concatenation of all FILEs, or stdin, sorted in natural order.
+
+  -z, --zero-terminated  records end in NUL instead of newline
+  -o, --output FILE      write to FILE instead of stdout
+      --rename WIDTH     treat records as paths and print the renames that would pad
+                         every number in their file names to WIDTH digits, without
+                         renaming anything
+  -h, --help             show this help
+";
+
+#[derive(Debug, PartialEq)]
+struct Args {
+    help: bool,
+    separator: u8,
+    output: Option<String>,
+    rename: Option<usize>,
+    files: Vec<String>,
+}
+
+fn parse_args<I: IntoIterator<Item = String>>(argv: I) -> Result<Args, String> {
+    let mut args = Args {
+        help: false,
+        separator: b'\n',
+        output: None,
+        rename: None,
+        files: vec![],
+    };
+
+    let mut iter = argv.into_iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-z" | "--zero-terminated" => args.separator = b'\0',
+            "-o" | "--output" => match iter.next() {
+                Some(path) => args.output = Some(path),
+                None => return Err(format!("{} needs a file name", arg)),
+            },
+            "--rename" => match iter.next().map(|width| width.parse()) {
+                Some(Ok(width)) => args.rename = Some(width),
+                _ => return Err(format!("{} needs a width", arg)),
+            },
+            "-h" | "--help" => args.help = true,
+            "--" => args.files.extend(iter.by_ref()),
+            _ if arg.starts_with('-') && arg != "-" => {
+                return Err(format!("unknown option {}", arg))
+            }
+            _ => args.files.push(arg),
+        }
+    }
+
+    Ok(args)
+}
+
+fn run(args: Args) -> io::Result<()> {
+    let mut input = vec![];
+    if args.files.is_empty() {
+        io::stdin().lock().read_to_end(&mut input)?;
+    }
+    for path in &args.files {
+        match path.as_str() {
+            "-" => io::stdin().lock().read_to_end(&mut input)?,
+            _ => BufReader::new(File::open(path)?).read_to_end(&mut input)?,
+        };
+        if input.last().is_some_and(|b| *b != args.separator) {
+            input.push(args.separator);
+        }
+    }
+
+    let writer: Box<dyn Write> = match &args.output {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(io::stdout().lock()),
+    };
+    let writer = io::BufWriter::new(writer);
+    match args.rename {
+        Some(width) => plan_renames(&input, writer, args.separator, width),
+        None => natural_sort_records(&input[..], writer, args.separator),
+    }
+}
+
+/// Prints `old -> new` for every path whose file name changes, or `old` NUL `new` NUL
+/// with `-z`, in natural order of the new names
+///
+/// Repeated records are planned once. Records that aren't valid UTF-8 are
+/// rejected rather than renamed to a mangled name.
+fn plan_renames<W: Write>(
+    input: &[u8],
+    mut writer: W,
+    separator: u8,
+    width: usize,
+) -> io::Result<()> {
+    let mut seen = HashSet::new();
+    let mut plan = vec![];
+    for record in input
+        .split(|b| *b == separator)
+        .filter(|record| !record.is_empty())
+    {
+        let old = std::str::from_utf8(record).map_err(|_| {
+            let message = format!("{} is not valid UTF-8", String::from_utf8_lossy(record));
+            io::Error::new(io::ErrorKind::InvalidData, message)
+        })?;
+        if !seen.insert(old) {
+            continue;
+        }
+
+        let path = Path::new(old);
+        let new = match path.file_name().and_then(|name| name.to_str()) {
+            Some(name) => path.with_file_name(normalize_numbers(name, width)),
+            None => path.to_path_buf(),
+        };
+        let new = new
+            .into_os_string()
+            .into_string()
+            .unwrap_or_else(|_| old.into());
+        plan.push((old, new));
+    }
+
+    let mut targets: HashMap<&str, &str> = HashMap::new();
+    for (old, new) in &plan {
+        if let Some(other) = targets.insert(new, old) {
+            let message = format!("{} and {} would both become {}", other, old, new);
+            return Err(io::Error::new(io::ErrorKind::AlreadyExists, message));
+        }
+    }
+
+    let mut renames: Vec<_> = plan.iter().filter(|(old, new)| old != new).collect();
+    renames.sort_by(|a, b| natural_cmp(&a.1, &b.1));
+
+    for (old, new) in renames {
+        match separator {
+            b'\0' => write!(writer, "{}\0{}\0", old, new)?,
+            _ => writeln!(writer, "{} -> {}", old, new)?,
+        }
+    }
+    writer.flush()
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) if args.help => {
+            print!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Ok(args) => args,
+        Err(message) => {
+            eprint!("natural-sort: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("natural-sort: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[test]
+fn test_parse_args() {
+    fn parse(argv: &[&str]) -> Result<Args, String> {
+        parse_args(argv.iter().map(|arg| String::from(*arg)))
+    }
+
+    assert_eq!(
+        parse(&[]),
+        Ok(Args {
+            help: false,
+            separator: b'\n',
+            output: None,
+            rename: None,
+            files: vec![],
+        })
+    );
+    assert_eq!(
+        parse(&["-z", "-o", "out", "--rename", "3", "a", "-", "--", "-b"]),
+        Ok(Args {
+            help: false,
+            separator: b'\0',
+            output: Some("out".into()),
+            rename: Some(3),
+            files: vec!["a".into(), "-".into(), "-b".into()],
+        })
+    );
+    assert!(parse(&["a", "--help"]).unwrap().help);
+    assert_eq!(parse(&["-o"]), Err("-o needs a file name".into()));
+    assert_eq!(
+        parse(&["--rename", "x"]),
+        Err("--rename needs a width".into())
+    );
+    assert_eq!(parse(&["-x"]), Err("unknown option -x".into()));
+}
+
+#[test]
+fn test_plan_renames() {
+    fn plan(input: &str, separator: u8, width: usize) -> io::Result<String> {
+        let mut out = vec![];
+        plan_renames(input.as_bytes(), &mut out, separator, width)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    assert_eq!(
+        plan("p/img10\np/img7\nimg1.png\n", b'\n', 3).unwrap(),
+        "img1.png -> img001.png\np/img7 -> p/img007\np/img10 -> p/img010\n"
+    );
+    assert_eq!(plan("a1\0b2\0", b'\0', 2).unwrap(), "a1\0a01\0b2\0b02\0");
+    assert_eq!(
+        plan("v2/img1\n", b'\n', 2).unwrap(),
+        "v2/img1 -> v2/img01\n"
+    );
+
+    // already padded and wider than the width: nothing to do
+    assert_eq!(plan("img007\nimg1234\n", b'\n', 3).unwrap(), "");
+    assert_eq!(plan("img0007\n", b'\n', 3).unwrap(), "img0007 -> img007\n");
+
+    // the same source twice is one rename, not a collision
+    assert_eq!(
+        plan("p/img7\np/img7\n", b'\n', 3).unwrap(),
+        "p/img7 -> p/img007\n"
+    );
+
+    let err = plan("img7\nimg07\n", b'\n', 3).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+    assert_eq!(err.to_string(), "img7 and img07 would both become img007");
+    let err = plan("img7\nimg007\n", b'\n', 3).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+
+    let mut out = vec![];
+    let err = plan_renames(b"img7\nbad\xff1\n", &mut out, b'\n', 3).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert!(out.is_empty());
+}
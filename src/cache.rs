@@ -0,0 +1,152 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use crate::key_bytes::natural_order_key;
+
+/// Remembers the parsed keys of recently sorted strings, for sorting the same values again and again
+///
+/// Each string is split into text and numbers once and kept as a byte key
+/// whose plain order is the natural order. Later sorts of the same strings
+/// only look their keys up. When more than `capacity` strings are cached, the
+/// least recently used ones are dropped.
+///
+/// # Examples
+/// ```
+/// use natural_sort::NaturalKeyCache;
+/// let mut cache = NaturalKeyCache::new(50_000);
+/// let entries = vec!["file10", "file9", "File1", "file1"];
+///
+/// for filter in ["", "file"] {
+///     let mut visible: Vec<&str> = entries.iter().copied().filter(|e| e.starts_with(filter)).collect();
+///     cache.sort(&mut visible);
+///     assert_eq!(visible.last(), Some(&"file10"));
+/// }
+/// assert_eq!(cache.len(), 4);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct NaturalKeyCache {
+    capacity: usize,
+    entries: HashMap<String, (Arc<[u8]>, u64)>,
+    recency: BTreeMap<u64, String>,
+    clock: u64,
+}
+
+impl NaturalKeyCache {
+    /// Creates a cache holding the keys of up to `capacity` strings
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - How many strings to remember, 0 disables caching
+    pub fn new(capacity: usize) -> Self {
+        NaturalKeyCache {
+            capacity,
+            ..NaturalKeyCache::default()
+        }
+    }
+
+    /// Returns how many strings are cached
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether no strings are cached
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Forgets every cached key
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    /// Compares two string slices like [`natural_cmp`](crate::natural_cmp), through the cache
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The left hand side
+    /// * `b` - The right hand side
+    pub fn compare(&mut self, a: &str, b: &str) -> Ordering {
+        self.key(a).cmp(&self.key(b))
+    }
+
+    /// Sorts a slice like [`natural_sort`](crate::natural_sort), through the cache
+    ///
+    /// Every value is looked up once per sort. The sort is stable.
+    ///
+    /// # Arguments
+    ///
+    /// * `vals` - A slice of string-like values
+    pub fn sort<T: AsRef<str>>(&mut self, vals: &mut [T]) {
+        vals.sort_by_cached_key(|val| self.key(val.as_ref()));
+    }
+
+    /// Returns the key of `s`, parsing and caching it if needed
+    fn key(&mut self, s: &str) -> Arc<[u8]> {
+        if self.capacity == 0 {
+            return natural_order_key(s).into();
+        }
+
+        self.clock += 1;
+        if let Some((key, used)) = self.entries.get_mut(s) {
+            let name = self
+                .recency
+                .remove(used)
+                .expect("every entry has a recency");
+            *used = self.clock;
+            self.recency.insert(self.clock, name);
+            return Arc::clone(key);
+        }
+
+        if self.entries.len() == self.capacity {
+            if let Some((_, oldest)) = self.recency.pop_first() {
+                self.entries.remove(&oldest);
+            }
+        }
+
+        let key: Arc<[u8]> = natural_order_key(s).into();
+        self.entries
+            .insert(s.to_string(), (Arc::clone(&key), self.clock));
+        self.recency.insert(self.clock, s.to_string());
+        key
+    }
+}
+
+#[test]
+fn test_natural_key_cache() {
+    let mut list = vec![
+        "z10a", "b23g", "z999", "z10", "x12z34", "x12z101", "z9", "z3", "z101", "z5", "z09",
+    ];
+    let mut expected = list.clone();
+    expected.sort_by(|a, b| crate::natural_cmp(a, b));
+
+    let mut cache = NaturalKeyCache::new(100);
+    cache.sort(&mut list);
+    assert_eq!(list, expected);
+    list.reverse();
+    let mut expected = list.clone();
+    expected.sort_by(|a, b| crate::natural_cmp(a, b));
+    cache.sort(&mut list);
+    assert_eq!(list, expected);
+    assert_eq!(cache.len(), 11);
+
+    assert_eq!(cache.compare("a01", "a1"), Ordering::Equal);
+    assert_eq!(cache.compare("a10", "a9"), Ordering::Greater);
+
+    let mut small = NaturalKeyCache::new(2);
+    small.compare("a", "b");
+    small.compare("a", "c");
+    assert_eq!(small.len(), 2);
+    assert!(small.entries.contains_key("a"));
+    assert!(small.entries.contains_key("c"));
+
+    let mut off = NaturalKeyCache::new(0);
+    let mut list = vec!["v10", "v9"];
+    off.sort(&mut list);
+    assert_eq!(list, vec!["v9", "v10"]);
+    assert!(off.is_empty());
+
+    small.clear();
+    assert!(small.is_empty());
+}
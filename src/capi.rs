@@ -0,0 +1,79 @@
+//! C API, enabled with the `capi` feature
+//!
+//! The matching header lives in `include/natural_sort.h`. To get a library to link against, build with
+//! `cargo rustc --release --features capi --crate-type staticlib` (or `cdylib`).
+//!
+//! Null pointers are treated as empty strings and invalid UTF-8 is replaced with `U+FFFD`
+//! before comparing.
+
+use std::borrow::Cow;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::slice;
+
+use crate::natural_cmp;
+
+unsafe fn from_cstr<'a>(s: *const c_char) -> Cow<'a, str> {
+    if s.is_null() {
+        return Cow::Borrowed("");
+    }
+    CStr::from_ptr(s).to_string_lossy()
+}
+
+unsafe fn from_utf8<'a>(s: *const u8, len: usize) -> Cow<'a, str> {
+    if s.is_null() || len == 0 {
+        return Cow::Borrowed("");
+    }
+    String::from_utf8_lossy(slice::from_raw_parts(s, len))
+}
+
+/// Compares two NUL terminated strings in a natural way
+///
+/// Returns a negative number, zero or a positive number like `strcmp`.
+///
+/// # Safety
+///
+/// `a` and `b` must each be null or point to a valid NUL terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn natural_cmp_cstr(a: *const c_char, b: *const c_char) -> c_int {
+    natural_cmp(&from_cstr(a), &from_cstr(b)) as c_int
+}
+
+/// Compares two UTF-8 buffers of known length in a natural way
+///
+/// Returns a negative number, zero or a positive number like `memcmp`.
+/// The buffers don't need to be NUL terminated.
+///
+/// # Safety
+///
+/// `a` must be null or point to at least `a_len` readable bytes, and likewise for `b`.
+#[no_mangle]
+pub unsafe extern "C" fn natural_cmp_utf8(
+    a: *const u8,
+    a_len: usize,
+    b: *const u8,
+    b_len: usize,
+) -> c_int {
+    natural_cmp(&from_utf8(a, a_len), &from_utf8(b, b_len)) as c_int
+}
+
+#[test]
+fn test_capi() {
+    use std::ffi::CString;
+    use std::ptr;
+
+    let z9 = CString::new("z9").unwrap();
+    let z10 = CString::new("z10").unwrap();
+
+    unsafe {
+        assert_eq!(natural_cmp_cstr(z9.as_ptr(), z10.as_ptr()), -1);
+        assert_eq!(natural_cmp_cstr(z10.as_ptr(), z9.as_ptr()), 1);
+        assert_eq!(natural_cmp_cstr(z10.as_ptr(), z10.as_ptr()), 0);
+        assert_eq!(natural_cmp_cstr(ptr::null(), z9.as_ptr()), -1);
+
+        let a = "z10 and then some";
+        let b = "z9";
+        assert_eq!(natural_cmp_utf8(a.as_ptr(), 3, b.as_ptr(), b.len()), 1);
+        assert_eq!(natural_cmp_utf8(ptr::null(), 0, ptr::null(), 0), 0);
+    }
+}
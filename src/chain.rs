@@ -0,0 +1,127 @@
+use std::cmp::Ordering;
+
+use crate::natural_cmp;
+
+type Comparator<'a, T> = Box<dyn Fn(&T, &T) -> Ordering + 'a>;
+
+/// Combines several sort keys into a single comparator
+///
+/// Keys are compared in the order they were added and the first one that
+/// differs decides the outcome.
+///
+/// # Examples
+/// ```
+/// use natural_sort::NaturalKeyChain;
+/// struct Row { host: String, file: String, size: u64 }
+/// let row = |host: &str, file: &str, size| Row { host: host.into(), file: file.into(), size };
+/// let mut rows = vec![row("web10", "log2", 5), row("web9", "log10", 7), row("web9", "log10", 3), row("web9", "log2", 1)];
+///
+/// let chain = NaturalKeyChain::new()
+///     .natural(|r: &Row| &r.host)
+///     .natural(|r: &Row| &r.file)
+///     .numeric(|r: &Row| r.size);
+/// rows.sort_by(|a, b| chain.compare(a, b));
+///
+/// let sizes: Vec<u64> = rows.iter().map(|r| r.size).collect();
+/// assert_eq!(sizes, vec![1, 3, 7, 5]);
+/// ```
+pub struct NaturalKeyChain<'a, T> {
+    keys: Vec<Comparator<'a, T>>,
+}
+
+impl<'a, T: 'a> NaturalKeyChain<'a, T> {
+    /// Creates an empty chain, which considers every pair of values equal
+    pub fn new() -> Self {
+        NaturalKeyChain { keys: vec![] }
+    }
+
+    /// Adds a key that is compared in a natural way
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Extracts a string-like field from a value
+    pub fn natural<K, F>(self, key: F) -> Self
+    where
+        K: AsRef<str> + ?Sized,
+        F: Fn(&T) -> &K + 'a,
+    {
+        self.by(move |a, b| natural_cmp(key(a).as_ref(), key(b).as_ref()))
+    }
+
+    /// Adds a key that is compared using its `Ord` implementation
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Extracts an ordered field from a value
+    pub fn numeric<K, F>(self, key: F) -> Self
+    where
+        K: Ord,
+        F: Fn(&T) -> K + 'a,
+    {
+        self.by(move |a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Adds an arbitrary comparator
+    ///
+    /// # Arguments
+    ///
+    /// * `cmp` - Compares two values
+    pub fn by<F>(mut self, cmp: F) -> Self
+    where
+        F: Fn(&T, &T) -> Ordering + 'a,
+    {
+        self.keys.push(Box::new(cmp));
+        self
+    }
+
+    /// Compares two values key by key
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The left hand side
+    /// * `b` - The right hand side
+    pub fn compare(&self, a: &T, b: &T) -> Ordering {
+        self.keys
+            .iter()
+            .map(|cmp| cmp(a, b))
+            .find(|ord| *ord != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<'a, T: 'a> Default for NaturalKeyChain<'a, T> {
+    fn default() -> Self {
+        NaturalKeyChain::new()
+    }
+}
+
+#[test]
+fn test_key_chain() {
+    let mut rows = vec![
+        ("host10", "b2", 1),
+        ("host9", "b10", 2),
+        ("host9", "b2", 3),
+        ("host9", "b2", 0),
+        ("host10", "a1", 9),
+    ];
+
+    let chain = NaturalKeyChain::new()
+        .natural(|r: &(&str, &str, u32)| r.0)
+        .natural(|r: &(&str, &str, u32)| r.1)
+        .numeric(|r: &(&str, &str, u32)| r.2);
+    rows.sort_by(|a, b| chain.compare(a, b));
+
+    assert_eq!(
+        rows,
+        vec![
+            ("host9", "b2", 0),
+            ("host9", "b2", 3),
+            ("host9", "b10", 2),
+            ("host10", "a1", 9),
+            ("host10", "b2", 1),
+        ]
+    );
+
+    let empty = NaturalKeyChain::default();
+    assert_eq!(empty.compare(&1, &2), Ordering::Equal);
+}
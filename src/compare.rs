@@ -0,0 +1,264 @@
+use std::cmp::Ordering;
+
+use crate::{numeric, scan, segment, Fractions};
+
+/// Compares two string slices in a natural way, ignoring case
+///
+/// Case is folded one character at a time while comparing, so nothing is
+/// allocated per call, unlike lowercasing both strings up front.
+///
+/// # Arguments
+///
+/// * `a` - The left hand side
+/// * `b` - The right hand side
+///
+/// # Examples
+/// ```
+/// use std::cmp::Ordering;
+/// use natural_sort::natural_cmp_ci;
+/// assert_eq!(natural_cmp_ci("File10", "file9"), Ordering::Greater);
+/// assert_eq!(natural_cmp_ci("IMG_02", "img_2"), Ordering::Equal);
+/// ```
+pub fn natural_cmp_ci(a: &str, b: &str) -> Ordering {
+    compare_runs(a, b, &Rules::default(), |x, y| {
+        x.chars()
+            .flat_map(char::to_lowercase)
+            .cmp(y.chars().flat_map(char::to_lowercase))
+    })
+}
+
+/// Returns a canonical form of a string that is the same for naturally equal strings
+///
+/// Numbers lose their leading zeros and are written with ASCII digits, and
+/// everything else is kept as is, so two
+/// strings share a signature exactly when [`natural_cmp`](crate::natural_cmp)
+/// considers them equal. Useful as a key for hashing or deduplication.
+///
+/// # Arguments
+///
+/// * `s` - The string to canonicalize
+///
+/// # Examples
+/// ```
+/// use natural_sort::natural_eq_signature;
+/// assert_eq!(natural_eq_signature("host007"), "host7");
+/// assert_eq!(natural_eq_signature("host007"), natural_eq_signature("host7"));
+/// assert_ne!(natural_eq_signature("host70"), natural_eq_signature("host7"));
+/// ```
+pub fn natural_eq_signature(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while !rest.is_empty() {
+        let (text, tail) = rest.split_at(scan::find_numeric(rest).unwrap_or(rest.len()));
+        out.push_str(text);
+
+        let (num, tail) = tail.split_at(scan::find_non_numeric(tail).unwrap_or(tail.len()));
+        if !num.is_empty() {
+            match numeric::trim_zeros(num) {
+                "" => out.push('0'),
+                trimmed => numeric::push_ascii_digits(&mut out, trimmed),
+            }
+        }
+
+        rest = tail;
+    }
+
+    out
+}
+
+/// How `compare_runs` reads numbers
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Rules {
+    pub(crate) fractions: Fractions,
+    pub(crate) max_segments: Option<usize>,
+    pub(crate) numeric_values: bool,
+}
+
+/// One comparison made while walking two strings, either of text runs or of numbers
+pub(crate) struct Step<'s> {
+    pub(crate) left: &'s str,
+    pub(crate) right: &'s str,
+    pub(crate) number: bool,
+    pub(crate) ordering: Ordering,
+}
+
+/// Walks both strings one text run and one number at a time
+///
+/// Text runs are compared with `text`, numbers by value unless `rules` say
+/// otherwise. A string that runs out first is less than the other one.
+pub(crate) fn compare_runs<F>(a: &str, b: &str, rules: &Rules, text: F) -> Ordering
+where
+    F: Fn(&str, &str) -> Ordering,
+{
+    walk_runs(a, b, rules, text, |_| {})
+}
+
+/// Compares like `compare_runs`, handing every step that isn't between two empty runs to `observe`
+pub(crate) fn walk_runs<'s, F, O>(
+    mut a: &'s str,
+    mut b: &'s str,
+    rules: &Rules,
+    text: F,
+    mut observe: O,
+) -> Ordering
+where
+    F: Fn(&str, &str) -> Ordering,
+    O: FnMut(Step<'s>),
+{
+    let mut after_number = false;
+    let mut numbers = 0;
+
+    loop {
+        if rules.max_segments == Some(numbers) {
+            return a.cmp(b);
+        }
+
+        let (a_text, a_rest) = a.split_at(text_end(a, rules));
+        let (b_text, b_rest) = b.split_at(text_end(b, rules));
+        let ord = text(a_text, b_text);
+        if !a_text.is_empty() || !b_text.is_empty() {
+            observe(Step {
+                left: a_text,
+                right: b_text,
+                number: false,
+                ordering: ord,
+            });
+        }
+        if ord != Ordering::Equal {
+            return ord;
+        }
+
+        let (a_num, a_rest) = a_rest.split_at(number_end(a_rest));
+        let (b_num, b_rest) = b_rest.split_at(number_end(b_rest));
+        let fraction = after_number && a_text == "." && b_text == ".";
+        let ord = match (a_num.is_empty(), b_num.is_empty()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => match (fraction, rules.fractions) {
+                _ if rules.numeric_values && !(is_run(a_num) && is_run(b_num)) => {
+                    compare_values(a_num, b_num)
+                }
+                (true, Fractions::Decimal) => {
+                    let zero = |c| numeric::decimal_value(c) == Some(0);
+                    let a = a_num.trim_end_matches(zero);
+                    let b = b_num.trim_end_matches(zero);
+                    numeric::digits(a).cmp(numeric::digits(b))
+                }
+                (true, Fractions::Filename) => numeric::digits(a_num).cmp(numeric::digits(b_num)),
+                _ => compare_digits(a_num, b_num),
+            },
+        };
+        observe(Step {
+            left: a_num,
+            right: b_num,
+            number: true,
+            ordering: ord,
+        });
+        if ord != Ordering::Equal {
+            return ord;
+        }
+
+        after_number = !fraction;
+        numbers += 1;
+        a = a_rest;
+        b = b_rest;
+    }
+}
+
+/// Returns where the text at the start of `s` ends
+///
+/// With `numeric_values`, characters like `'½'` end the text as well.
+fn text_end(s: &str, rules: &Rules) -> usize {
+    match rules.numeric_values {
+        true => s.find(|c| scan::is_digit(c) || numeric::value(c).is_some()),
+        false => scan::find_numeric(s),
+    }
+    .unwrap_or(s.len())
+}
+
+/// Returns where the number at the start of `s` ends, after a run of digits
+/// or a single character with a numeric value
+fn number_end(s: &str) -> usize {
+    match s.chars().next() {
+        Some(c) if scan::is_digit(c) => scan::find_non_numeric(s).unwrap_or(s.len()),
+        Some(c) if numeric::value(c).is_some() => c.len_utf8(),
+        _ => 0,
+    }
+}
+
+/// Returns whether a number is a run of digits rather than a character with a value
+fn is_run(num: &str) -> bool {
+    num.starts_with(scan::is_digit)
+}
+
+/// Compares two numbers by value, where at least one is a character like `'½'`
+fn compare_values(a: &str, b: &str) -> Ordering {
+    let (a_num, a_den) = value(a);
+    let (b_num, b_den) = value(b);
+    (a_num * b_den).cmp(&(b_num * a_den))
+}
+
+/// Returns the value of a number as a fraction, runs of digits that are too
+/// long for a character value to matter saturate
+fn value(num: &str) -> (i128, i128) {
+    let mut chars = num.chars();
+    match (chars.next().and_then(numeric::value), chars.next()) {
+        (Some((numerator, denominator)), None) => (i128::from(numerator), i128::from(denominator)),
+        _ => {
+            let value = segment::digits_value(num).min(u128::from(u64::MAX));
+            (value as i128, 1)
+        }
+    }
+}
+
+/// Compares two runs of digits by value, however long they are and whatever
+/// script their digits are from
+fn compare_digits(a: &str, b: &str) -> Ordering {
+    let a = numeric::trim_zeros(a);
+    let b = numeric::trim_zeros(b);
+    a.chars()
+        .count()
+        .cmp(&b.chars().count())
+        .then_with(|| numeric::digits(a).cmp(numeric::digits(b)))
+}
+
+#[test]
+fn test_natural_cmp_ci() {
+    assert_eq!(natural_cmp_ci("abc", "ABC"), Ordering::Equal);
+    assert_eq!(natural_cmp_ci("Z9", "z10"), Ordering::Less);
+    assert_eq!(natural_cmp_ci("b2", "A10"), Ordering::Greater);
+    assert_eq!(natural_cmp_ci("ÄPFEL1", "äpfel1"), Ordering::Equal);
+    assert_eq!(natural_cmp_ci("x10A", "x10"), Ordering::Greater);
+    assert_eq!(natural_cmp_ci("x10", "X10a"), Ordering::Less);
+    assert_eq!(
+        natural_cmp_ci("v99999999999999999999999", "V100000000000000000000000"),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn test_natural_eq_signature() {
+    assert_eq!(natural_eq_signature(""), "");
+    assert_eq!(natural_eq_signature("abc"), "abc");
+    assert_eq!(natural_eq_signature("a000b00c0"), "a0b0c0");
+    assert_eq!(natural_eq_signature("a٠٣"), "a3");
+    assert_eq!(natural_eq_signature("a٠"), "a0");
+    assert_eq!(natural_eq_signature("007x0010"), "7x10");
+
+    for (a, b) in [
+        ("host007", "host7"),
+        ("v01.02", "v1.2"),
+        ("x", "x0"),
+        ("a1b", "a01c"),
+    ] {
+        assert_eq!(
+            natural_eq_signature(a) == natural_eq_signature(b),
+            crate::natural_cmp(a, b) == Ordering::Equal,
+            "{} {}",
+            a,
+            b
+        );
+    }
+}
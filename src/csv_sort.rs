@@ -0,0 +1,60 @@
+use std::io;
+
+use crate::natural_cmp;
+
+/// Copies CSV rows from `reader` to `writer`, ordered naturally by one column
+///
+/// If the reader is configured with headers they are written first, untouched.
+/// Rows that don't have the requested column sort as if it was empty.
+/// The sort is stable, so rows with equal keys keep their input order.
+///
+/// # Arguments
+///
+/// * `reader` - The CSV input
+/// * `writer` - The CSV output
+/// * `column` - Zero based index of the column to sort by
+///
+/// # Examples
+/// ```
+/// use natural_sort::natural_sort_csv;
+/// let input = "name,size\nfile10,3\nfile9,1\nfile1,2\n";
+/// let mut reader = csv::Reader::from_reader(input.as_bytes());
+/// let mut writer = csv::Writer::from_writer(vec![]);
+/// natural_sort_csv(&mut reader, &mut writer, 0).unwrap();
+/// let output = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+/// assert_eq!(output, "name,size\nfile1,2\nfile9,1\nfile10,3\n");
+/// ```
+pub fn natural_sort_csv<R: io::Read, W: io::Write>(
+    reader: &mut csv::Reader<R>,
+    writer: &mut csv::Writer<W>,
+    column: usize,
+) -> csv::Result<()> {
+    if reader.has_headers() {
+        writer.write_record(reader.headers()?)?;
+    }
+
+    let mut rows = reader.records().collect::<csv::Result<Vec<_>>>()?;
+    rows.sort_by(|a, b| natural_cmp(a.get(column).unwrap_or(""), b.get(column).unwrap_or("")));
+
+    for row in &rows {
+        writer.write_record(row)?;
+    }
+    writer.flush()?;
+
+    Ok(())
+}
+
+#[test]
+fn test_natural_sort_csv() {
+    let input = "z10,a\nz9,b\nz101\nx12z34,c\nz9,d\n";
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(input.as_bytes());
+    let mut writer = csv::WriterBuilder::new().flexible(true).from_writer(vec![]);
+
+    natural_sort_csv(&mut reader, &mut writer, 1).unwrap();
+
+    let output = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+    assert_eq!(output, "z101\nz10,a\nz9,b\nx12z34,c\nz9,d\n");
+}
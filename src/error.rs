@@ -0,0 +1,62 @@
+use std::error::Error;
+use std::fmt;
+use std::str::Utf8Error;
+
+/// Why a fallible comparison or sort was rejected
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NaturalError {
+    /// A value isn't valid UTF-8
+    InvalidUtf8 {
+        /// The position of the value, `0` or `1` for the two sides of a comparison
+        index: usize,
+        /// Where decoding the value failed
+        source: Utf8Error,
+    },
+    /// A byte key wasn't produced by `natural_key_bytes`
+    MalformedKey {
+        /// Where decoding the key failed
+        offset: usize,
+    },
+    /// A line of an ordering spec isn't two strings and a relation
+    MalformedSpec {
+        /// The line, counting from 1
+        line: usize,
+    },
+    /// A number has more significant digits than a fixed width key can hold
+    NumberTooWide {
+        /// The significant digits of the number
+        digits: usize,
+        /// The width the key pads numbers to
+        width: usize,
+    },
+}
+
+impl fmt::Display for NaturalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NaturalError::InvalidUtf8 { index, source } => {
+                write!(f, "value {} is not valid UTF-8: {}", index, source)
+            }
+            NaturalError::MalformedKey { offset } => {
+                write!(f, "malformed natural key at byte {}", offset)
+            }
+            NaturalError::MalformedSpec { line } => {
+                write!(f, "malformed ordering spec at line {}", line)
+            }
+            NaturalError::NumberTooWide { digits, width } => {
+                write!(f, "number with {} digits is wider than {}", digits, width)
+            }
+        }
+    }
+}
+
+impl Error for NaturalError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NaturalError::InvalidUtf8 { source, .. } => Some(source),
+            NaturalError::MalformedKey { .. }
+            | NaturalError::MalformedSpec { .. }
+            | NaturalError::NumberTooWide { .. } => None,
+        }
+    }
+}
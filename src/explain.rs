@@ -0,0 +1,145 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::compare::{walk_runs, Rules, Step};
+use crate::segment::digits_value;
+use crate::Segment;
+
+/// One pair of segments compared by [`explain_cmp`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceStep<'a> {
+    /// The segment of the left hand side, `None` if it has none at this point
+    pub left: Option<Segment<'a>>,
+    /// The segment of the right hand side, `None` if it has none at this point
+    pub right: Option<Segment<'a>>,
+    /// How the two segments compared
+    pub ordering: Ordering,
+}
+
+/// Every segment pair [`explain_cmp`] looked at, in order
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompareTrace<'a> {
+    /// The compared pairs, the last one decides unless the strings are equal
+    pub steps: Vec<TraceStep<'a>>,
+    /// The result, the same as [`natural_cmp`](crate::natural_cmp) returns
+    pub ordering: Ordering,
+}
+
+impl CompareTrace<'_> {
+    /// Returns the step that decided the outcome, `None` if the strings are equal
+    pub fn decisive(&self) -> Option<&TraceStep<'_>> {
+        self.steps
+            .last()
+            .filter(|step| step.ordering != Ordering::Equal)
+    }
+}
+
+impl fmt::Display for CompareTrace<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let describe = |segment: &Option<Segment>| match segment {
+            None => String::from("nothing"),
+            Some(Segment::Alpha(text)) => format!("text {:?}", text),
+            Some(Segment::Number { raw, .. }) => format!("number {}", raw),
+        };
+
+        for step in &self.steps {
+            writeln!(
+                f,
+                "{} {} {}",
+                describe(&step.left),
+                symbol(step.ordering),
+                describe(&step.right)
+            )?;
+        }
+        write!(f, "result: {:?}", self.ordering)
+    }
+}
+
+fn symbol(ord: Ordering) -> &'static str {
+    match ord {
+        Ordering::Less => "<",
+        Ordering::Equal => "=",
+        Ordering::Greater => ">",
+    }
+}
+
+/// Compares like [`natural_cmp`](crate::natural_cmp) and records how the result came about
+///
+/// Meant for debugging and bug reports about ordering, it allocates a step
+/// per compared segment pair.
+///
+/// # Arguments
+///
+/// * `a` - The left hand side
+/// * `b` - The right hand side
+///
+/// # Examples
+/// ```
+/// use std::cmp::Ordering;
+/// use natural_sort::{explain_cmp, Segment};
+/// let trace = explain_cmp("img12.png", "img9.png");
+/// assert_eq!(trace.ordering, Ordering::Greater);
+///
+/// let step = trace.decisive().unwrap();
+/// assert_eq!(step.left, Some(Segment::Number { raw: "12", value: 12 }));
+/// assert_eq!(step.right, Some(Segment::Number { raw: "9", value: 9 }));
+///
+/// println!("{}", trace);
+/// // text "img" = text "img"
+/// // number 12 > number 9
+/// // result: Greater
+/// ```
+pub fn explain_cmp<'a>(a: &'a str, b: &'a str) -> CompareTrace<'a> {
+    let mut steps = vec![];
+    let ordering = walk_runs(a, b, &Rules::default(), str::cmp, |step: Step<'a>| {
+        let segment = |s: &'a str| match (s.is_empty(), step.number) {
+            (true, _) => None,
+            (false, true) => Some(Segment::Number {
+                raw: s,
+                value: digits_value(s),
+            }),
+            (false, false) => Some(Segment::Alpha(s)),
+        };
+
+        steps.push(TraceStep {
+            left: segment(step.left),
+            right: segment(step.right),
+            ordering: step.ordering,
+        });
+    });
+
+    CompareTrace { steps, ordering }
+}
+
+#[test]
+fn test_explain_cmp() {
+    let trace = explain_cmp("a01", "a1");
+    assert_eq!(trace.ordering, Ordering::Equal);
+    assert_eq!(trace.steps.len(), 2);
+    assert_eq!(trace.decisive(), None);
+
+    let trace = explain_cmp("z10", "z10a");
+    assert_eq!(trace.ordering, Ordering::Less);
+    let step = trace.decisive().unwrap();
+    assert_eq!(step.left, None);
+    assert_eq!(step.right, Some(Segment::Alpha("a")));
+
+    let trace = explain_cmp("b2", "a10");
+    assert_eq!(trace.steps.len(), 1);
+    assert_eq!(
+        trace.to_string(),
+        "text \"b\" > text \"a\"\nresult: Greater"
+    );
+
+    let trace = explain_cmp("a٣", "a10");
+    assert_eq!(
+        trace.decisive().unwrap().left,
+        Some(Segment::Number {
+            raw: "٣", value: 3
+        })
+    );
+
+    for (a, b) in [("x12z34", "x12z101"), ("", "1"), ("v1.2", "v1.2")] {
+        assert_eq!(explain_cmp(a, b).ordering, crate::natural_cmp(a, b));
+    }
+}
@@ -0,0 +1,206 @@
+//! Sorts more keys than fit in memory by spilling sorted runs to disk and merging them
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+use crate::natural_cmp;
+
+/// Numbers the run files of this process, so concurrent sorts never collide
+static NEXT_RUN: AtomicUsize = AtomicUsize::new(0);
+
+/// A sorted chunk of keys written to a file, removed again when dropped
+///
+/// Each key is stored as its length in bytes, eight bytes little endian,
+/// followed by its UTF-8 bytes.
+#[derive(Debug)]
+pub(crate) struct Run {
+    path: PathBuf,
+}
+
+impl Run {
+    /// Sorts `keys` and writes them to a new file in `dir`, leaving `keys` empty
+    pub(crate) fn write(dir: &Path, keys: &mut Vec<String>) -> io::Result<Run> {
+        let path = dir.join(format!(
+            "natural-sort-{}-{}.run",
+            process::id(),
+            NEXT_RUN.fetch_add(1, AtomicOrdering::Relaxed)
+        ));
+        let file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        let run = Run { path };
+
+        keys.sort_by(|a, b| natural_cmp(a, b));
+        let mut writer = BufWriter::new(file);
+        for key in keys.drain(..) {
+            writer.write_all(&(key.len() as u64).to_le_bytes())?;
+            writer.write_all(key.as_bytes())?;
+        }
+        writer.flush()?;
+
+        Ok(run)
+    }
+
+    fn open(&self) -> io::Result<RunReader> {
+        Ok(RunReader(BufReader::new(File::open(&self.path)?)))
+    }
+}
+
+impl Drop for Run {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+struct RunReader(BufReader<File>);
+
+impl RunReader {
+    fn next(&mut self) -> io::Result<Option<String>> {
+        let mut len = [0; 8];
+        match self.0.read_exact(&mut len) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e),
+        }
+
+        let mut key = vec![0; u64::from_le_bytes(len) as usize];
+        self.0.read_exact(&mut key)?;
+        String::from_utf8(key)
+            .map(Some)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+/// Where keys come from while merging: a run on disk or the last chunk, still in memory
+enum Source {
+    Run(RunReader),
+    Memory(std::vec::IntoIter<String>),
+}
+
+impl Source {
+    fn next(&mut self) -> io::Result<Option<String>> {
+        match self {
+            Source::Run(reader) => reader.next(),
+            Source::Memory(keys) => Ok(keys.next()),
+        }
+    }
+}
+
+/// The smallest unread key of one source
+struct Head {
+    key: String,
+    source: usize,
+}
+
+impl Ord for Head {
+    /// Reversed, so the max-heap pops the naturally smallest key, and of equal
+    /// keys the one from the earliest source, which keeps the merge stable
+    fn cmp(&self, other: &Self) -> Ordering {
+        natural_cmp(&other.key, &self.key).then_with(|| other.source.cmp(&self.source))
+    }
+}
+
+impl PartialOrd for Head {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Head {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Head {}
+
+/// Merges the runs, followed by the keys that never left memory, in natural order
+///
+/// Equal keys come out in the order the runs were written, so the whole sort is stable.
+pub(crate) struct Merge {
+    // Keeps the files around until the merge is dropped
+    _runs: Vec<Run>,
+    sources: Vec<Source>,
+    heap: BinaryHeap<Head>,
+}
+
+impl Merge {
+    pub(crate) fn new(runs: Vec<Run>, mut memory: Vec<String>) -> io::Result<Merge> {
+        memory.sort_by(|a, b| natural_cmp(a, b));
+
+        let mut sources = runs
+            .iter()
+            .map(|run| run.open().map(Source::Run))
+            .collect::<io::Result<Vec<_>>>()?;
+        sources.push(Source::Memory(memory.into_iter()));
+
+        let mut heap = BinaryHeap::with_capacity(sources.len());
+        for (source, keys) in sources.iter_mut().enumerate() {
+            if let Some(key) = keys.next()? {
+                heap.push(Head { key, source });
+            }
+        }
+
+        Ok(Merge {
+            _runs: runs,
+            sources,
+            heap,
+        })
+    }
+}
+
+impl Iterator for Merge {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Head { key, source } = self.heap.pop()?;
+        match self.sources[source].next() {
+            Ok(Some(next)) => self.heap.push(Head { key: next, source }),
+            Ok(None) => {}
+            Err(e) => {
+                self.heap.clear();
+                return Some(Err(e));
+            }
+        }
+        Some(Ok(key))
+    }
+}
+
+#[test]
+fn test_external_merge() {
+    let dir = std::env::temp_dir();
+    let mut runs = vec![];
+    let mut first = vec!["z10".to_string(), "a01".to_string(), "m".to_string()];
+    runs.push(Run::write(&dir, &mut first).unwrap());
+    assert!(first.is_empty());
+    let mut second = vec!["z9".to_string(), "a1".to_string(), "new\nline".to_string()];
+    runs.push(Run::write(&dir, &mut second).unwrap());
+    let paths: Vec<PathBuf> = runs.iter().map(|run| run.path.clone()).collect();
+
+    let memory = vec!["a001".to_string(), "z100".to_string(), String::new()];
+    let merged: Vec<String> = Merge::new(runs, memory)
+        .unwrap()
+        .collect::<io::Result<_>>()
+        .unwrap();
+    assert_eq!(
+        merged,
+        vec![
+            "",
+            "a01",
+            "a1",
+            "a001",
+            "m",
+            "new\nline",
+            "z9",
+            "z10",
+            "z100"
+        ]
+    );
+    assert!(paths.iter().all(|path| !path.exists()));
+}
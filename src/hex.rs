@@ -0,0 +1,103 @@
+//! Recognizes colon or dash separated hex groups like MAC addresses
+
+/// Returns the value of a one or two digit hex group at the start of `s`, its
+/// length and whether it is two digits with a letter, like `"0b"`
+fn group(s: &str) -> Option<(u8, usize, bool)> {
+    let len = s
+        .bytes()
+        .take(3)
+        .take_while(|b| b.is_ascii_hexdigit())
+        .count();
+    let letters = len == 2 && s[..len].bytes().any(|b| b.is_ascii_alphabetic());
+    match len {
+        1 | 2 => Some((u8::from_str_radix(&s[..len], 16).ok()?, len, letters)),
+        _ => None,
+    }
+}
+
+/// Reads a run of at least two groups sharing one separator
+///
+/// Unless every group has two digits, one of them needs to be two digits with
+/// a letter, so runs like `"a-99-1"` are left alone instead of reading `99` as `0x99`.
+/// Returns the values, the separator and the length of the run.
+fn run(s: &str) -> Option<(Vec<u8>, char, usize)> {
+    let (first, mut end, mut letters) = group(s)?;
+    let sep = s[end..].chars().next().filter(|c| *c == ':' || *c == '-')?;
+    let mut values = vec![first];
+    let mut pairs = end == 2;
+
+    while s[end..].starts_with(sep) {
+        match group(&s[end + 1..]) {
+            Some((value, len, has_letters)) => {
+                values.push(value);
+                end += 1 + len;
+                letters |= has_letters;
+                pairs &= len == 2;
+            }
+            None => break,
+        }
+    }
+
+    let boundary = !s[end..].starts_with(char::is_alphanumeric);
+    match values.len() >= 2 && (pairs || letters) && boundary {
+        true => Some((values, sep, end)),
+        false => None,
+    }
+}
+
+/// Replaces every hex group with its decimal value
+///
+/// `"sw-aa:0b:10"` becomes `"sw-170:11:16"`, so the groups compare as numbers.
+/// A run needs at least two groups of one or two hex digits, joined by the same
+/// `:` or `-`, and must not touch other letters or digits. Its groups must all
+/// have two digits or include a two digit group with a letter.
+pub(crate) fn replace_hex_groups(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    let mut after_alphanumeric = false;
+
+    while let Some(c) = rest.chars().next() {
+        if !after_alphanumeric {
+            if let Some((values, sep, len)) = run(rest) {
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        out.push(sep);
+                    }
+                    out.push_str(&value.to_string());
+                }
+                rest = &rest[len..];
+                after_alphanumeric = true;
+                continue;
+            }
+        }
+
+        out.push(c);
+        after_alphanumeric = c.is_alphanumeric();
+        rest = &rest[c.len_utf8()..];
+    }
+
+    out
+}
+
+#[test]
+fn test_replace_hex_groups() {
+    assert_eq!(replace_hex_groups("sw-aa:0b:10"), "sw-170:11:16");
+    assert_eq!(replace_hex_groups("sw-aa:0b:2"), "sw-170:11:2");
+    assert_eq!(replace_hex_groups("00-1A-2b-FF"), "0-26-43-255");
+    assert_eq!(replace_hex_groups("aa:bb-cc"), "170:187-cc");
+    assert_eq!(replace_hex_groups("abc:de"), "abc:de");
+    assert_eq!(replace_hex_groups("vaa:bb"), "vaa:bb");
+    assert_eq!(replace_hex_groups("aa:bbx"), "aa:bbx");
+    assert_eq!(replace_hex_groups("aa:"), "aa:");
+    assert_eq!(replace_hex_groups("fe:"), "fe:");
+    assert_eq!(replace_hex_groups("port ab:cd: up"), "port 171:205: up");
+    assert_eq!(replace_hex_groups("ä aa:bb"), "ä 170:187");
+    assert_eq!(replace_hex_groups("äaa:bb"), "äaa:bb");
+    assert_eq!(replace_hex_groups("aa:bbä"), "aa:bbä");
+    assert_eq!(replace_hex_groups("aa:bb٣"), "aa:bb٣");
+    assert_eq!(replace_hex_groups("00:11:22"), "0:17:34");
+    assert_eq!(replace_hex_groups("a-99-1"), "a-99-1");
+    assert_eq!(replace_hex_groups("1-2"), "1-2");
+    assert_eq!(replace_hex_groups("1:a"), "1:a");
+    assert_eq!(replace_hex_groups("1:0a"), "1:10");
+}
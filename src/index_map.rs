@@ -0,0 +1,56 @@
+use indexmap::{IndexMap, IndexSet};
+
+use crate::natural_cmp;
+
+/// Reorders the entries of an `IndexMap` naturally by key, in place
+///
+/// The sort is stable, so keys that compare naturally equal keep their order.
+///
+/// # Arguments
+///
+/// * `map` - The map to reorder
+///
+/// # Examples
+/// ```
+/// use indexmap::IndexMap;
+/// use natural_sort::natural_sort_index_map;
+/// let mut map = IndexMap::from([("disk10", 3), ("disk2", 2), ("disk1", 1)]);
+/// natural_sort_index_map(&mut map);
+/// assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec!["disk1", "disk2", "disk10"]);
+/// ```
+pub fn natural_sort_index_map<K: AsRef<str>, V, S>(map: &mut IndexMap<K, V, S>) {
+    map.sort_by(|a, _, b, _| natural_cmp(a.as_ref(), b.as_ref()));
+}
+
+/// Reorders the values of an `IndexSet` naturally, in place
+///
+/// # Arguments
+///
+/// * `set` - The set to reorder
+///
+/// # Examples
+/// ```
+/// use indexmap::IndexSet;
+/// use natural_sort::natural_sort_index_set;
+/// let mut set = IndexSet::from(["v10", "v9", "v1"]);
+/// natural_sort_index_set(&mut set);
+/// assert_eq!(set.into_iter().collect::<Vec<_>>(), vec!["v1", "v9", "v10"]);
+/// ```
+pub fn natural_sort_index_set<T: AsRef<str>, S>(set: &mut IndexSet<T, S>) {
+    set.sort_by(|a, b| natural_cmp(a.as_ref(), b.as_ref()));
+}
+
+#[test]
+fn test_natural_sort_index_map() {
+    let mut map: IndexMap<String, usize> = ["z10", "z9", "z09", "a"]
+        .into_iter()
+        .enumerate()
+        .map(|(i, k)| (k.to_string(), i))
+        .collect();
+    natural_sort_index_map(&mut map);
+
+    let entries: Vec<(&str, usize)> = map.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+    assert_eq!(entries, vec![("a", 3), ("z9", 1), ("z09", 2), ("z10", 0)]);
+    assert_eq!(map.get_index_of("z10"), Some(3));
+    assert_eq!(map["z9"], 1);
+}
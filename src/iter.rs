@@ -0,0 +1,319 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{natural_cmp, segments, Segment};
+
+/// Returns the naturally smallest element of an iterator
+///
+/// Runs in a single pass without sorting. If several elements are equally small the first one is returned.
+///
+/// # Arguments
+///
+/// * `vals` - Anything that iterates over string-like values
+///
+/// # Examples
+/// ```
+/// use natural_sort::natural_min;
+/// assert_eq!(natural_min(vec!["build10", "build9", "build100"]), Some("build9"));
+/// ```
+pub fn natural_min<I>(vals: I) -> Option<I::Item>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    vals.into_iter()
+        .min_by(|a, b| natural_cmp(a.as_ref(), b.as_ref()))
+}
+
+/// Returns the naturally largest element of an iterator
+///
+/// Runs in a single pass without sorting. If several elements are equally large the last one is returned.
+///
+/// # Arguments
+///
+/// * `vals` - Anything that iterates over string-like values
+///
+/// # Examples
+/// ```
+/// use natural_sort::natural_max;
+/// assert_eq!(natural_max(vec!["build10", "build9", "build100"]), Some("build100"));
+/// ```
+pub fn natural_max<I>(vals: I) -> Option<I::Item>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    vals.into_iter()
+        .max_by(|a, b| natural_cmp(a.as_ref(), b.as_ref()))
+}
+
+/// Returns both the naturally smallest and largest elements of an iterator
+///
+/// Ties are resolved the same way as [`natural_min`] and [`natural_max`].
+/// With a single element, both halves of the pair hold a copy of it.
+///
+/// # Arguments
+///
+/// * `vals` - Anything that iterates over string-like values
+///
+/// # Examples
+/// ```
+/// use natural_sort::natural_minmax;
+/// assert_eq!(natural_minmax(vec!["build10", "build9", "build100"]), Some(("build9", "build100")));
+/// ```
+pub fn natural_minmax<I>(vals: I) -> Option<(I::Item, I::Item)>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str> + Clone,
+{
+    let mut iter = vals.into_iter();
+    let first = iter.next()?;
+    let (mut min, mut max) = (first.clone(), first);
+
+    for val in iter {
+        if natural_cmp(val.as_ref(), min.as_ref()) == Ordering::Less {
+            min = val;
+        } else if natural_cmp(val.as_ref(), max.as_ref()) != Ordering::Less {
+            max = val;
+        }
+    }
+
+    Some((min, max))
+}
+
+/// Buckets values by their leading non-numeric segment
+///
+/// The prefix is everything before the first number, exactly as the natural comparison splits it,
+/// so values without any digits form a group of their own. Members of each group are sorted naturally.
+///
+/// # Arguments
+///
+/// * `vals` - Anything that iterates over string-like values
+///
+/// # Examples
+/// ```
+/// use natural_sort::natural_group_by_prefix;
+/// let groups = natural_group_by_prefix(vec!["db1.log", "app10.log", "app2.log"]);
+/// assert_eq!(groups["app"], vec!["app2.log", "app10.log"]);
+/// assert_eq!(groups["db"], vec!["db1.log"]);
+/// ```
+pub fn natural_group_by_prefix<I>(vals: I) -> BTreeMap<String, Vec<I::Item>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut vals: Vec<I::Item> = vals.into_iter().collect();
+    vals.sort_by(|a, b| natural_cmp(a.as_ref(), b.as_ref()));
+
+    let mut groups: BTreeMap<String, Vec<I::Item>> = BTreeMap::new();
+    for val in vals {
+        let prefix = match segments(val.as_ref()).next() {
+            Some(Segment::Alpha(text)) => String::from(text),
+            _ => String::new(),
+        };
+        groups.entry(prefix).or_default().push(val);
+    }
+
+    groups
+}
+
+/// Returns the entries of a hash map sorted naturally by key
+///
+/// Keys that compare naturally equal (`"a01"` and `"a1"`) are ordered by their
+/// plain text, so the result doesn't depend on the order of the map.
+///
+/// # Arguments
+///
+/// * `map` - A hash map with string-like keys
+///
+/// # Examples
+/// ```
+/// use std::collections::HashMap;
+/// use natural_sort::natural_sort_keyed_map;
+/// let map = HashMap::from([("disk10", 3), ("disk2", 2), ("disk1", 1)]);
+/// let entries = natural_sort_keyed_map(&map);
+/// assert_eq!(entries, vec![(&"disk1", &1), (&"disk2", &2), (&"disk10", &3)]);
+/// ```
+pub fn natural_sort_keyed_map<K, V, S>(map: &HashMap<K, V, S>) -> Vec<(&K, &V)>
+where
+    K: AsRef<str>,
+{
+    let mut entries: Vec<(&K, &V)> = map.iter().collect();
+    entries.sort_by(|(a, _), (b, _)| {
+        let (a, b) = (a.as_ref(), b.as_ref());
+        natural_cmp(a, b).then_with(|| a.cmp(b))
+    });
+    entries
+}
+
+/// Natural sorting as iterator adapters, implemented for every iterator
+///
+/// Each method collects the iterator, sorts it stably and hands back an
+/// iterator over the result, like the `sorted` adapters of `itertools`.
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use natural_sort::NaturalIterExt;
+/// let paths = [Path::new("out/frame10.png"), Path::new("out/frame9.png")];
+/// let sorted: Vec<_> = paths
+///     .iter()
+///     .natural_sorted_by_key(|p| p.file_name().and_then(|n| n.to_str()).unwrap_or(""))
+///     .collect();
+/// assert_eq!(sorted, vec![&paths[1], &paths[0]]);
+/// ```
+pub trait NaturalIterExt: Iterator + Sized {
+    /// Returns the elements in natural order
+    ///
+    /// # Examples
+    /// ```
+    /// use natural_sort::NaturalIterExt;
+    /// let sorted: Vec<&str> = ["v10", "v9", "v1"].into_iter().natural_sorted().collect();
+    /// assert_eq!(sorted, vec!["v1", "v9", "v10"]);
+    /// ```
+    fn natural_sorted(self) -> std::vec::IntoIter<Self::Item>
+    where
+        Self::Item: AsRef<str>,
+    {
+        let mut vals: Vec<Self::Item> = self.collect();
+        vals.sort_by(|a, b| natural_cmp(a.as_ref(), b.as_ref()));
+        vals.into_iter()
+    }
+
+    /// Returns the elements in natural order of a string borrowed from each
+    ///
+    /// `key` runs twice per comparison, so it should be cheap, like a field access.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Returns the string to sort an element by
+    ///
+    /// # Examples
+    /// ```
+    /// use natural_sort::NaturalIterExt;
+    /// let tracks = vec![(10, "Track 10"), (2, "Track 2")];
+    /// let sorted: Vec<_> = tracks.into_iter().natural_sorted_by_key(|t| t.1).collect();
+    /// assert_eq!(sorted, vec![(2, "Track 2"), (10, "Track 10")]);
+    /// ```
+    fn natural_sorted_by_key<F>(self, mut key: F) -> std::vec::IntoIter<Self::Item>
+    where
+        F: FnMut(&Self::Item) -> &str,
+    {
+        let mut vals: Vec<Self::Item> = self.collect();
+        vals.sort_by(|a, b| natural_cmp(key(a), key(b)));
+        vals.into_iter()
+    }
+
+    /// Returns the elements in natural order of a key computed once per element
+    ///
+    /// Use this when the key has to be built, like a lossy conversion of an
+    /// `OsStr`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Builds the string to sort an element by
+    ///
+    /// # Examples
+    /// ```
+    /// use natural_sort::NaturalIterExt;
+    /// let sorted: Vec<u32> = [10, 9, 100].into_iter().natural_sorted_by_cached_key(|n| format!("run{}", n)).collect();
+    /// assert_eq!(sorted, vec![9, 10, 100]);
+    /// ```
+    fn natural_sorted_by_cached_key<K, F>(self, mut key: F) -> std::vec::IntoIter<Self::Item>
+    where
+        K: AsRef<str>,
+        F: FnMut(&Self::Item) -> K,
+    {
+        let mut keyed: Vec<(K, Self::Item)> = self.map(|v| (key(&v), v)).collect();
+        keyed.sort_by(|(a, _), (b, _)| natural_cmp(a.as_ref(), b.as_ref()));
+        keyed
+            .into_iter()
+            .map(|(_, v)| v)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+impl<I: Iterator> NaturalIterExt for I {}
+
+#[test]
+fn test_natural_extremes() {
+    let list = vec![
+        "z10a", "b23g", "z999", "z10", "x12z34", "x12z101", "z9", "z3", "z101", "z5",
+    ];
+
+    assert_eq!(natural_min(&list), Some(&"b23g"));
+    assert_eq!(natural_max(&list), Some(&"z999"));
+    assert_eq!(natural_minmax(list), Some(("b23g", "z999")));
+
+    let empty: Vec<String> = vec![];
+    assert_eq!(natural_min(&empty), None);
+    assert_eq!(natural_minmax(empty), None);
+
+    assert_eq!(natural_minmax(vec!["a1"]), Some(("a1", "a1")));
+    assert_eq!(
+        natural_minmax(vec!["a1", "a01", "a001"]),
+        Some(("a1", "a001"))
+    );
+}
+
+#[test]
+fn test_natural_group_by_prefix() {
+    let groups = natural_group_by_prefix(vec![
+        "app10.log",
+        "db1.log",
+        "app2.log",
+        "README",
+        "app1.log",
+        "7z.exe",
+    ]);
+
+    let keys: Vec<&str> = groups.keys().map(|k| k.as_str()).collect();
+    assert_eq!(keys, vec!["", "README", "app", "db"]);
+    assert_eq!(groups[""], vec!["7z.exe"]);
+    assert_eq!(groups["README"], vec!["README"]);
+    assert_eq!(groups["app"], vec!["app1.log", "app2.log", "app10.log"]);
+    assert_eq!(groups["db"], vec!["db1.log"]);
+}
+
+#[test]
+fn test_natural_sort_keyed_map() {
+    let map: HashMap<String, u32> = ["z10", "z9", "z09", "a1"]
+        .into_iter()
+        .enumerate()
+        .map(|(i, k)| (k.to_string(), i as u32))
+        .collect();
+
+    let keys: Vec<&str> = natural_sort_keyed_map(&map)
+        .into_iter()
+        .map(|(k, _)| k.as_str())
+        .collect();
+    assert_eq!(keys, vec!["a1", "z09", "z9", "z10"]);
+    assert!(natural_sort_keyed_map(&HashMap::<&str, ()>::new()).is_empty());
+}
+
+#[test]
+fn test_natural_iter_ext() {
+    let sorted: Vec<String> = ["z10", "z9", "z09", "a"]
+        .iter()
+        .map(|s| s.to_string())
+        .natural_sorted()
+        .collect();
+    assert_eq!(sorted, vec!["a", "z9", "z09", "z10"]);
+
+    let entries = [("b", "img10"), ("a", "img9"), ("c", "img010")];
+    let by_value: Vec<&str> = entries
+        .iter()
+        .natural_sorted_by_key(|e| e.1)
+        .map(|e| e.0)
+        .collect();
+    assert_eq!(by_value, vec!["a", "b", "c"]);
+
+    let by_cached: Vec<i32> = (1..=12)
+        .rev()
+        .natural_sorted_by_cached_key(|n| format!("part{}", n % 5))
+        .collect();
+    assert_eq!(by_cached, vec![10, 5, 11, 6, 1, 12, 7, 2, 8, 3, 9, 4]);
+
+    assert_eq!(std::iter::empty::<&str>().natural_sorted().count(), 0);
+}
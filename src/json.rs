@@ -0,0 +1,57 @@
+use serde_json::{Map, Value};
+
+use crate::natural_cmp;
+
+/// Recursively reorders the keys of every JSON object in a natural way
+///
+/// Objects nested inside arrays are reordered as well. The `serde_json` feature enables
+/// `serde_json/preserve_order`, so the new key order is kept when the value is serialized.
+///
+/// # Arguments
+///
+/// * `value` - The JSON value to reorder in place
+///
+/// # Examples
+/// ```
+/// use natural_sort::natural_sort_json_keys;
+/// let mut value: serde_json::Value = serde_json::from_str(r#"{"step10":{"b2":0,"b1":0},"step2":0,"step1":0}"#).unwrap();
+/// natural_sort_json_keys(&mut value);
+/// assert_eq!(value.to_string(), r#"{"step1":0,"step2":0,"step10":{"b1":0,"b2":0}}"#);
+/// ```
+pub fn natural_sort_json_keys(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(String, Value)> = std::mem::take(map).into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| natural_cmp(a, b));
+
+            let mut sorted = Map::with_capacity(entries.len());
+            for (key, mut val) in entries {
+                natural_sort_json_keys(&mut val);
+                sorted.insert(key, val);
+            }
+            *map = sorted;
+        }
+        Value::Array(vals) => {
+            for val in vals.iter_mut() {
+                natural_sort_json_keys(val);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[test]
+fn test_natural_sort_json_keys() {
+    let mut value = serde_json::json!({
+        "step10": [{ "z10": 1, "z9": 2 }],
+        "step2": null,
+        "step1": { "x2": true, "x1": false },
+    });
+
+    natural_sort_json_keys(&mut value);
+
+    assert_eq!(
+        value.to_string(),
+        r#"{"step1":{"x1":false,"x2":true},"step2":null,"step10":[{"z9":2,"z10":1}]}"#
+    );
+}
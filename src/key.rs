@@ -0,0 +1,122 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::natural_cmp;
+
+/// A string type that can be compared naturally without converting it
+///
+/// Implemented for the standard string types. Interned or small string types
+/// from other crates only need to hand out their text:
+///
+/// ```
+/// use natural_sort::{natural_sort_keys, NaturalOrdKey};
+///
+/// struct Symbol(&'static str);
+///
+/// impl NaturalOrdKey for Symbol {
+///     fn natural_str(&self) -> &str {
+///         self.0
+///     }
+/// }
+///
+/// let mut list = vec![Symbol("v10"), Symbol("v9")];
+/// natural_sort_keys(&mut list);
+/// assert_eq!(list[0].0, "v9");
+/// ```
+pub trait NaturalOrdKey {
+    /// Returns the text to compare
+    fn natural_str(&self) -> &str;
+
+    /// Compares two keys in a natural way
+    fn natural_cmp(&self, other: &Self) -> Ordering {
+        natural_cmp(self.natural_str(), other.natural_str())
+    }
+}
+
+impl NaturalOrdKey for str {
+    fn natural_str(&self) -> &str {
+        self
+    }
+}
+
+impl NaturalOrdKey for String {
+    fn natural_str(&self) -> &str {
+        self
+    }
+}
+
+impl NaturalOrdKey for Cow<'_, str> {
+    fn natural_str(&self) -> &str {
+        self
+    }
+}
+
+impl NaturalOrdKey for Box<str> {
+    fn natural_str(&self) -> &str {
+        self
+    }
+}
+
+impl NaturalOrdKey for Rc<str> {
+    fn natural_str(&self) -> &str {
+        self
+    }
+}
+
+impl NaturalOrdKey for Arc<str> {
+    fn natural_str(&self) -> &str {
+        self
+    }
+}
+
+impl<T: NaturalOrdKey + ?Sized> NaturalOrdKey for &T {
+    fn natural_str(&self) -> &str {
+        (**self).natural_str()
+    }
+}
+
+/// Sorts a slice of [`NaturalOrdKey`] values in a natural way
+///
+/// The sort is stable.
+///
+/// # Arguments
+///
+/// * `vals` - A slice of keys
+///
+/// # Examples
+/// ```
+/// use std::borrow::Cow;
+/// use std::sync::Arc;
+/// use natural_sort::natural_sort_keys;
+///
+/// let mut list: Vec<Cow<str>> = vec![Cow::Borrowed("z10"), Cow::Owned("z9".to_string())];
+/// natural_sort_keys(&mut list);
+/// assert_eq!(list, vec!["z9", "z10"]);
+///
+/// let mut list: Vec<Arc<str>> = vec![Arc::from("tab10"), Arc::from("tab2")];
+/// natural_sort_keys(&mut list);
+/// assert_eq!(&*list[0], "tab2");
+/// ```
+pub fn natural_sort_keys<T: NaturalOrdKey>(vals: &mut [T]) {
+    vals.sort_by(|a, b| a.natural_cmp(b))
+}
+
+#[test]
+fn test_natural_ord_key() {
+    let mut boxed: Vec<Box<str>> = vec!["z10a".into(), "z9".into(), "z10".into()];
+    natural_sort_keys(&mut boxed);
+    assert_eq!(boxed, vec!["z9".into(), "z10".into(), "z10a".into()]);
+
+    let mut refs: Vec<&str> = vec!["b2", "a10", "a9"];
+    natural_sort_keys(&mut refs);
+    assert_eq!(refs, vec!["a9", "a10", "b2"]);
+
+    let rc: Rc<str> = Rc::from("x01");
+    assert_eq!(rc.natural_cmp(&Rc::from("x1")), Ordering::Equal);
+    assert_eq!(
+        String::from("x2").natural_cmp(&String::from("x10")),
+        Ordering::Less
+    );
+}
@@ -0,0 +1,243 @@
+//! Encodes strings into byte keys whose plain byte order is the natural order
+//!
+//! A key is a sequence of steps, one per text run and number:
+//!
+//! * text is written with every `0x00` escaped as `0x00 0xff` and ends in `0x00 0x01`,
+//!   so a shorter run sorts before any longer run it is a prefix of
+//! * after each text run, `0x01` marks the end of the string and `0x02` a number
+//! * a number is written as the count of its significant digits followed by their
+//!   values as ASCII digits, whatever script they are written in
+//!
+//! The original digits of every number, with their leading zeros, come last,
+//! after the end marker, so they only break ties between strings that compare
+//! naturally equal and the original string can be decoded exactly.
+
+use crate::{numeric, scan, NaturalError};
+
+const TEXT_END: [u8; 2] = [0x00, 0x01];
+const ESCAPED_NUL: [u8; 2] = [0x00, 0xff];
+const END: u8 = 0x01;
+const NUMBER: u8 = 0x02;
+
+/// Writes an order preserving variable length integer: its byte count, then its big endian bytes
+fn push_varint(out: &mut Vec<u8>, n: usize) {
+    let bytes = (n as u64).to_be_bytes();
+    let skip = bytes.iter().take_while(|b| **b == 0).count();
+    out.push((bytes.len() - skip) as u8);
+    out.extend_from_slice(&bytes[skip..]);
+}
+
+/// Encodes a string into a key whose byte order matches [`natural_cmp`](crate::natural_cmp)
+///
+/// Comparing two keys with plain byte comparison, like `memcmp` or the default
+/// order of most key value stores, gives the natural order of the strings.
+/// Strings that are naturally equal (`"a01"` and `"a1"`) still get distinct
+/// keys, ordered by their original digits. [`natural_key_decode`] turns a key back into its string.
+///
+/// # Arguments
+///
+/// * `s` - The string to encode
+///
+/// # Examples
+/// ```
+/// use natural_sort::natural_key_bytes;
+/// let mut keys = vec![natural_key_bytes("z10"), natural_key_bytes("z9"), natural_key_bytes("z101")];
+/// keys.sort();
+/// assert_eq!(keys[0], natural_key_bytes("z9"));
+/// assert_eq!(keys[2], natural_key_bytes("z101"));
+/// ```
+pub fn natural_key_bytes(s: &str) -> Vec<u8> {
+    encode(s, true)
+}
+
+/// Encodes like [`natural_key_bytes`] without the trailing padding, so naturally
+/// equal strings get equal keys
+pub(crate) fn natural_order_key(s: &str) -> Vec<u8> {
+    encode(s, false)
+}
+
+fn encode(s: &str, padding: bool) -> Vec<u8> {
+    let mut out = Vec::with_capacity(s.len() + 8);
+    let mut raws = vec![];
+    let mut rest = s;
+
+    loop {
+        let (text, tail) = rest.split_at(scan::find_numeric(rest).unwrap_or(rest.len()));
+        for b in text.bytes() {
+            match b {
+                0 => out.extend_from_slice(&ESCAPED_NUL),
+                _ => out.push(b),
+            }
+        }
+        out.extend_from_slice(&TEXT_END);
+
+        if tail.is_empty() {
+            out.push(END);
+            break;
+        }
+
+        let (num, tail) = tail.split_at(scan::find_non_numeric(tail).unwrap_or(tail.len()));
+        let digits = numeric::trim_zeros(num);
+        out.push(NUMBER);
+        push_varint(&mut out, digits.chars().count());
+        out.extend(numeric::digits(digits).map(|digit| b'0' + digit));
+        raws.push(num);
+        rest = tail;
+    }
+
+    if padding {
+        for raw in raws {
+            push_varint(&mut out, raw.len());
+            out.extend_from_slice(raw.as_bytes());
+        }
+    }
+    out
+}
+
+/// Reads keys produced by [`natural_key_bytes`]
+struct Decoder<'a> {
+    key: &'a [u8],
+    pos: usize,
+}
+
+impl Decoder<'_> {
+    fn malformed(&self) -> NaturalError {
+        NaturalError::MalformedKey { offset: self.pos }
+    }
+
+    fn byte(&mut self) -> Result<u8, NaturalError> {
+        let b = *self.key.get(self.pos).ok_or_else(|| self.malformed())?;
+        self.pos += 1;
+        Ok(b)
+    }
+
+    fn varint(&mut self) -> Result<usize, NaturalError> {
+        let len = self.byte()?;
+        if len > 8 {
+            return Err(self.malformed());
+        }
+        (0..len).try_fold(0usize, |n, _| {
+            let b = self.byte()?;
+            n.checked_mul(256)
+                .map(|n| n + usize::from(b))
+                .ok_or_else(|| self.malformed())
+        })
+    }
+
+    fn text(&mut self, out: &mut Vec<u8>) -> Result<(), NaturalError> {
+        loop {
+            match self.byte()? {
+                0 => match self.byte()? {
+                    0x01 => return Ok(()),
+                    0xff => out.push(0),
+                    _ => return Err(self.malformed()),
+                },
+                b => out.push(b),
+            }
+        }
+    }
+
+    fn digits(&mut self, count: usize) -> Result<(), NaturalError> {
+        for _ in 0..count {
+            if !self.byte()?.is_ascii_digit() {
+                return Err(self.malformed());
+            }
+        }
+        Ok(())
+    }
+
+    fn raw(&mut self) -> Result<&[u8], NaturalError> {
+        let len = self.varint()?;
+        let start = self.pos;
+        self.pos = start.checked_add(len).ok_or_else(|| self.malformed())?;
+        self.key
+            .get(start..self.pos)
+            .ok_or_else(|| self.malformed())
+    }
+}
+
+/// Decodes a key produced by [`natural_key_bytes`] back into its string
+///
+/// # Arguments
+///
+/// * `key` - The encoded key
+///
+/// # Examples
+/// ```
+/// use natural_sort::{natural_key_bytes, natural_key_decode};
+/// let key = natural_key_bytes("img007.png");
+/// assert_eq!(natural_key_decode(&key).unwrap(), "img007.png");
+/// assert!(natural_key_decode(b"garbage").is_err());
+/// ```
+pub fn natural_key_decode(key: &[u8]) -> Result<String, NaturalError> {
+    let mut decoder = Decoder { key, pos: 0 };
+    let mut parts: Vec<(Vec<u8>, bool)> = vec![];
+
+    loop {
+        let mut text = vec![];
+        decoder.text(&mut text)?;
+        match decoder.byte()? {
+            END => {
+                parts.push((text, false));
+                break;
+            }
+            NUMBER => {
+                let count = decoder.varint()?;
+                decoder.digits(count)?;
+                parts.push((text, true));
+            }
+            _ => return Err(decoder.malformed()),
+        }
+    }
+
+    let mut out = vec![];
+    for (text, number) in parts {
+        out.extend(text);
+        if number {
+            out.extend_from_slice(decoder.raw()?);
+        }
+    }
+    if decoder.pos != key.len() {
+        return Err(decoder.malformed());
+    }
+
+    let offset = decoder.pos;
+    let s = String::from_utf8(out).map_err(|_| NaturalError::MalformedKey { offset })?;
+    // A number that wasn't split at its run boundaries can't come from encoding
+    match natural_key_bytes(&s) == key {
+        true => Ok(s),
+        false => Err(NaturalError::MalformedKey { offset }),
+    }
+}
+
+#[test]
+fn test_natural_key_bytes() {
+    use std::cmp::Ordering;
+
+    let alphabet = ["a", "b", "\0", "0", "00", "7", "10", "٣", "٠", "3", ".", ""];
+    let mut strings = vec![];
+    for x in alphabet {
+        for y in alphabet {
+            for z in alphabet {
+                strings.push(format!("{}{}{}", x, y, z));
+            }
+        }
+    }
+
+    let keys: Vec<Vec<u8>> = strings.iter().map(|s| natural_key_bytes(s)).collect();
+    for (a, a_key) in strings.iter().zip(&keys) {
+        assert_eq!(natural_key_decode(a_key).as_deref(), Ok(a.as_str()));
+        for (b, b_key) in strings.iter().zip(&keys) {
+            match crate::natural_cmp(a, b) {
+                Ordering::Equal => assert_eq!(a_key == b_key, a == b, "{:?} {:?}", a, b),
+                ord => assert_eq!(a_key.cmp(b_key), ord, "{:?} {:?}", a, b),
+            }
+        }
+    }
+
+    let big = "n123456789012345678901234567890";
+    assert_eq!(natural_key_decode(&natural_key_bytes(big)).unwrap(), big);
+    assert!(natural_key_decode(&[]).is_err());
+    assert!(natural_key_decode(&[0, 1, 9]).is_err());
+    assert!(natural_key_decode(&[0, 1, 1, 0]).is_err());
+}
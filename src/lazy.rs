@@ -0,0 +1,138 @@
+use std::cmp::Ordering;
+use std::iter::Peekable;
+
+use crate::{numeric, scan};
+
+/// Compares two streams of characters in a natural way, without collecting them
+///
+/// Gives the same result as [`natural_cmp`](crate::natural_cmp) on the
+/// collected strings, but reads only as far as the first deciding segment and
+/// never buffers, so keys can come straight from a file or a memory map.
+/// Numbers of any length are compared digit by digit.
+///
+/// # Arguments
+///
+/// * `a` - The left hand side
+/// * `b` - The right hand side
+///
+/// # Examples
+/// ```
+/// use std::cmp::Ordering;
+/// use natural_sort::natural_cmp_chars;
+/// assert_eq!(natural_cmp_chars("file9".chars(), "file10".chars()), Ordering::Less);
+///
+/// let endless = "x1".chars().chain(std::iter::repeat('z'));
+/// assert_eq!(natural_cmp_chars("x2".chars(), endless), Ordering::Greater);
+/// ```
+pub fn natural_cmp_chars<A, B>(a: A, b: B) -> Ordering
+where
+    A: IntoIterator<Item = char>,
+    B: IntoIterator<Item = char>,
+{
+    let mut a = a.into_iter().peekable();
+    let mut b = b.into_iter().peekable();
+
+    loop {
+        let ord = compare_text(&mut a, &mut b);
+        if ord != Ordering::Equal {
+            return ord;
+        }
+
+        let ord = match (starts_number(&mut a), starts_number(&mut b)) {
+            (false, false) => return Ordering::Equal,
+            (false, true) => Ordering::Less,
+            (true, false) => Ordering::Greater,
+            (true, true) => compare_number(&mut a, &mut b),
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+}
+
+/// Takes the next character if it is a digit
+fn next_numeric<I: Iterator<Item = char>>(it: &mut Peekable<I>) -> Option<char> {
+    it.next_if(|c| scan::is_digit(*c))
+}
+
+/// Takes the next character unless it is a digit
+fn next_text<I: Iterator<Item = char>>(it: &mut Peekable<I>) -> Option<char> {
+    it.next_if(|c| !scan::is_digit(*c))
+}
+
+fn starts_number<I: Iterator<Item = char>>(it: &mut Peekable<I>) -> bool {
+    it.peek().is_some_and(|c| scan::is_digit(*c))
+}
+
+/// Compares the text runs at the front of both streams, consuming them
+fn compare_text<A, B>(a: &mut Peekable<A>, b: &mut Peekable<B>) -> Ordering
+where
+    A: Iterator<Item = char>,
+    B: Iterator<Item = char>,
+{
+    loop {
+        match (next_text(a), next_text(b)) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x != y => return x.cmp(&y),
+            _ => {}
+        }
+    }
+}
+
+/// Compares the numbers at the front of both streams by value, consuming them
+///
+/// After the leading zeros the longer number wins, and between numbers of the
+/// same length the first differing digit decides.
+fn compare_number<A, B>(a: &mut Peekable<A>, b: &mut Peekable<B>) -> Ordering
+where
+    A: Iterator<Item = char>,
+    B: Iterator<Item = char>,
+{
+    let zero = |c: &char| numeric::decimal_value(*c) == Some(0);
+    while a.next_if(zero).is_some() {}
+    while b.next_if(zero).is_some() {}
+
+    let mut first_difference = Ordering::Equal;
+    loop {
+        match (next_numeric(a), next_numeric(b)) {
+            (None, None) => return first_difference,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) => {
+                if first_difference == Ordering::Equal {
+                    first_difference = numeric::decimal_value(x).cmp(&numeric::decimal_value(y));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_natural_cmp_chars() {
+    let inputs = [
+        "", "a", "ab", "a1", "a01", "a10", "a2b", "a2", "z10a", "z10", "007", "0", "00", "x0y",
+        "١٢", "12", "٠١٢", "a٣", "a٢", "a3", "1½", "½", "x²", "x2", "v1.2.10", "v1.2.9", "ä1",
+        "a 1", "10a", "9z",
+    ];
+
+    for a in inputs {
+        for b in inputs {
+            assert_eq!(
+                natural_cmp_chars(a.chars(), b.chars()),
+                crate::natural_cmp(a, b),
+                "{:?} {:?}",
+                a,
+                b
+            );
+        }
+    }
+
+    let long = "9".repeat(10_000);
+    let longer = format!("1{}", "0".repeat(10_000));
+    assert_eq!(
+        natural_cmp_chars(long.chars(), longer.chars()),
+        Ordering::Less
+    );
+}
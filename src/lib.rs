@@ -1,152 +1,148 @@
 use std::cmp::Ordering;
-use std::cmp::PartialOrd;
-use std::fmt;
 
-struct StringParts {
-    alpha: String,
-    numeric: Option<u64>,
-    remainder: Option<String>,
-}
-
-impl StringParts {
-    fn split(s: &str) -> StringParts {
-        // find first number
-        let index = match s.find(|c: char| c.is_numeric()) {
-            Some(n) => n,
-            None => {
-                return StringParts {
-                    alpha: String::from(s),
-                    numeric: None,
-                    remainder: None,
-                };
-            }
-        };
-
-        let (alpha, num) = s.split_at(index);
-
-        // find end of first part
-        let index = match num.find(|c: char| !c.is_numeric()) {
-            Some(n) => n,
-            None => {
-                return StringParts {
-                    alpha: String::from(alpha),
-                    numeric: Some(num.parse::<u64>().unwrap()),
-                    remainder: None,
-                };
-            }
-        };
-
-        let (num, rem) = num.split_at(index);
-
-        StringParts {
-            alpha: String::from(alpha),
-            numeric: Some(num.parse::<u64>().unwrap()),
-            remainder: Some(String::from(rem)),
-        }
-    }
-
-    fn join(&self) -> String {
-        format!("{}", self)
-    }
-}
-
-impl fmt::Display for StringParts {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match &self.numeric {
-            Some(n) => match &self.remainder {
-                Some(s) => {
-                    write!(f, "{}{}{}", self.alpha, n, s)
-                }
-                None => {
-                    write!(f, "{}{}", self.alpha, n)
-                }
-            },
-            None => {
-                write!(f, "{}", self.alpha)
-            }
-        }
-    }
-}
-
-impl PartialEq for StringParts {
-    fn eq(&self, other: &StringParts) -> bool {
-        self.join() == other.join()
-    }
+mod alphanumeric;
+mod ascii;
+#[cfg(feature = "bench-util")]
+pub mod bench;
+mod cache;
+#[cfg(feature = "capi")]
+pub mod capi;
+mod chain;
+mod compare;
+#[cfg(feature = "csv")]
+mod csv_sort;
+mod error;
+mod explain;
+#[cfg(feature = "tokio")]
+mod external;
+mod hex;
+#[cfg(feature = "indexmap")]
+mod index_map;
+mod iter;
+#[cfg(feature = "serde_json")]
+mod json;
+mod key;
+mod key_bytes;
+mod lazy;
+mod natord;
+mod natural;
+mod normalize;
+mod numeric;
+mod options;
+#[cfg(feature = "ordinals")]
+mod ordinal;
+mod partial;
+mod path;
+#[cfg(feature = "pyo3")]
+pub mod python;
+mod radix;
+mod records;
+mod scan;
+mod segment;
+mod sorted;
+mod sorter;
+mod spec;
+mod sql_key;
+#[cfg(feature = "tokio")]
+mod stream;
+mod strverscmp;
+mod uuid;
+#[cfg(feature = "wasm-bindgen")]
+pub mod wasm;
 
-    fn ne(&self, other: &StringParts) -> bool {
-        !self.eq(other)
-    }
-}
-
-impl PartialOrd for StringParts {
-    fn partial_cmp(&self, other: &StringParts) -> Option<Ordering> {
-        if self.gt(other) {
-            Some(Ordering::Greater)
-        } else if self.lt(other) {
-            Some(Ordering::Less)
-        } else {
-            Some(Ordering::Equal)
-        }
-    }
-
-    fn lt(&self, other: &StringParts) -> bool {
-        !self.ge(other)
-    }
-
-    fn le(&self, other: &StringParts) -> bool {
-        if self.eq(other) {
-            return true;
-        }
-        self.lt(other)
-    }
-
-    fn gt(&self, other: &StringParts) -> bool {
-        if self.alpha != other.alpha {
-            return self.alpha > other.alpha;
-        }
-
-        if self.numeric != other.numeric {
-            match self.numeric {
-                None => {
-                    return false;
-                }
-                Some(n) => match other.numeric {
-                    None => {
-                        return true;
-                    }
-                    Some(n2) => {
-                        return n > n2;
-                    }
-                },
-            }
-        }
-
-        if self.remainder != other.remainder {
-            match &self.remainder {
-                None => return false,
-                Some(r1) => match &other.remainder {
-                    None => {
-                        return false;
-                    }
-                    Some(r2) => {
-                        let remainder_self = StringParts::split(&r1);
-                        let remainder_other = StringParts::split(&r2);
-
-                        return remainder_self.gt(&remainder_other);
-                    }
-                },
-            }
-        } else {
-            return false;
-        }
-    }
+pub use ascii::{is_natural_sorted_ascii, natural_cmp_ascii};
+pub use cache::NaturalKeyCache;
+pub use chain::NaturalKeyChain;
+pub use compare::{natural_cmp_ci, natural_eq_signature};
+#[cfg(feature = "csv")]
+pub use csv_sort::natural_sort_csv;
+pub use error::NaturalError;
+pub use explain::{explain_cmp, CompareTrace, TraceStep};
+#[cfg(feature = "indexmap")]
+pub use index_map::{natural_sort_index_map, natural_sort_index_set};
+pub use iter::{
+    natural_group_by_prefix, natural_max, natural_min, natural_minmax, natural_sort_keyed_map,
+    NaturalIterExt,
+};
+#[cfg(feature = "serde_json")]
+pub use json::natural_sort_json_keys;
+pub use key::{natural_sort_keys, NaturalOrdKey};
+pub use key_bytes::{natural_key_bytes, natural_key_decode};
+pub use lazy::natural_cmp_chars;
+pub use natural::{natural_map, natural_set, Natural, NaturalMap, NaturalSet, NaturalString};
+/// Derives `Ord` and friends using natural comparison of string fields
+///
+/// Fields marked with `#[natural]` are compared in declaration order.
+/// Without any marked fields only the first field is compared.
+///
+/// # Examples
+/// ```
+/// use natural_sort::NaturalOrd;
+///
+/// #[derive(Debug, NaturalOrd)]
+/// struct Tag(String);
+///
+/// #[derive(Debug, NaturalOrd)]
+/// struct Asset {
+///     #[natural]
+///     dir: String,
+///     #[natural]
+///     file: &'static str,
+///     size: u64,
+/// }
+///
+/// assert!(Tag("v9".into()) < Tag("v10".into()));
+///
+/// let a = Asset { dir: "img2".into(), file: "tex10", size: 1 };
+/// let b = Asset { dir: "img2".into(), file: "tex9", size: 2 };
+/// assert!(a > b);
+/// assert_eq!(a, Asset { dir: "img02".into(), file: "tex10", size: 3 });
+/// ```
+#[cfg(feature = "derive")]
+pub use natural_sort_derive::NaturalOrd;
+pub use normalize::normalize_numbers;
+#[cfg(feature = "unicode")]
+pub use options::Strength;
+pub use options::{CharClass, DecimalDigit, Fractions, Mode, NaturalOptions};
+pub use partial::NaturalPartialSorter;
+pub use path::{natural_cmp_dir_entry, read_dir_sorted, HiddenFiles, PathSortOptions};
+pub use radix::natural_sort_radix;
+pub use records::{natural_sort_lines, natural_sort_records};
+pub use segment::{natural_common_prefix, segments, Segment};
+pub use sorted::{natural_insert, natural_insertion_point, natural_nearest, natural_range};
+pub use sorter::{natural_rank, NaturalSorter};
+pub use spec::{verify_spec, SpecViolation, ORDERING_SPEC};
+pub use sql_key::natural_sql_key;
+#[cfg(feature = "tokio")]
+pub use stream::{NaturalSortStream, SortedStream};
 
-    fn ge(&self, other: &StringParts) -> bool {
-        if self.eq(other) {
-            return true;
-        }
-        self.gt(other)
-    }
+/// Compares two string slices in a natural way
+///
+/// Numbers are runs of decimal digits in any script. Numeric characters with
+/// a value of their own, like `'½'`, `'²'` or `'③'`, are compared as text, see
+/// [`NaturalOptions::numeric_values`] to read them as numbers.
+///
+/// A string that runs out of segments first is less than the other one,
+/// whichever side it is on and whether the other continues with text or a
+/// number: `"asdf" < "asdf1"` and `"z10" < "z10a"`. So this is a total order,
+/// `natural_cmp(a, b)` is always `natural_cmp(b, a).reverse()`.
+///
+/// Never panics, whatever the input. This is checked by the fuzz target in `fuzz/`.
+///
+/// # Arguments
+///
+/// * `a` - The left hand side
+/// * `b` - The right hand side
+///
+/// # Examples
+/// ```
+/// use std::cmp::Ordering;
+/// use natural_sort::natural_cmp;
+/// assert_eq!(natural_cmp("z9", "z10"), Ordering::Less);
+/// assert_eq!(natural_cmp("b2", "a10"), Ordering::Greater);
+/// ```
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    compare::compare_runs(a, b, &compare::Rules::default(), str::cmp)
 }
 
 /// Sorts a vector of &str in a natural way
@@ -165,12 +161,66 @@ impl PartialOrd for StringParts {
 /// assert_eq!(list, expected);
 /// ```
 pub fn natural_sort(vals: &mut Vec<&str>) {
+    vals.sort_by(|a, b| natural_cmp(a, b))
+}
+
+/// Compares two byte strings in a natural way, rejecting invalid UTF-8
+///
+/// # Arguments
+///
+/// * `a` - The left hand side
+/// * `b` - The right hand side
+///
+/// # Examples
+/// ```
+/// use std::cmp::Ordering;
+/// use natural_sort::{try_natural_cmp, NaturalError};
+/// assert_eq!(try_natural_cmp(b"z9", b"z10"), Ok(Ordering::Less));
+/// assert!(matches!(
+///     try_natural_cmp(b"z9", b"z\xff"),
+///     Err(NaturalError::InvalidUtf8 { index: 1, .. })
+/// ));
+/// ```
+pub fn try_natural_cmp(a: &[u8], b: &[u8]) -> Result<Ordering, NaturalError> {
+    let a = decode(0, a)?;
+    let b = decode(1, b)?;
+    Ok(natural_cmp(a, b))
+}
+
+/// Sorts byte strings in a natural way, rejecting invalid UTF-8
+///
+/// Every value is checked before sorting, so on error `vals` is left untouched.
+///
+/// # Arguments
+///
+/// * `vals` - A slice of byte strings
+///
+/// # Examples
+/// ```
+/// use natural_sort::try_natural_sort;
+/// let mut list: Vec<&[u8]> = vec![b"z10", b"z9"];
+/// try_natural_sort(&mut list).unwrap();
+/// assert_eq!(list, vec![b"z9" as &[u8], b"z10"]);
+///
+/// let mut list: Vec<&[u8]> = vec![b"z10", b"\xc3"];
+/// assert!(try_natural_sort(&mut list).is_err());
+/// assert_eq!(list, vec![b"z10" as &[u8], b"\xc3"]);
+/// ```
+pub fn try_natural_sort<T: AsRef<[u8]>>(vals: &mut [T]) -> Result<(), NaturalError> {
+    for (index, val) in vals.iter().enumerate() {
+        decode(index, val.as_ref())?;
+    }
+
     vals.sort_by(|a, b| {
-        let sa = StringParts::split(a);
-        let sb = StringParts::split(b);
+        let a = std::str::from_utf8(a.as_ref()).unwrap_or_default();
+        let b = std::str::from_utf8(b.as_ref()).unwrap_or_default();
+        natural_cmp(a, b)
+    });
+    Ok(())
+}
 
-        sa.partial_cmp(&sb).unwrap_or(Ordering::Equal)
-    })
+fn decode(index: usize, bytes: &[u8]) -> Result<&str, NaturalError> {
+    std::str::from_utf8(bytes).map_err(|source| NaturalError::InvalidUtf8 { index, source })
 }
 
 #[test]
@@ -187,10 +237,23 @@ fn test_natural_sort() {
     assert_eq!(list, expected);
 }
 
+#[test]
+fn test_numeric_symbols() {
+    assert_eq!(natural_cmp("x½", "x½"), Ordering::Equal);
+    assert_eq!(natural_cmp("x½2", "x½10"), Ordering::Less);
+    assert_eq!(natural_cmp("2²", "2³"), Ordering::Less);
+    assert_eq!(natural_cmp("③10", "③9"), Ordering::Greater);
+    assert_eq!(natural_cmp("a١٢", "a٩"), Ordering::Greater);
+
+    let mut list = vec!["v⅓", "v2", "v½", "v10"];
+    natural_sort(&mut list);
+    assert_eq!(list, vec!["v2", "v10", "v½", "v⅓"]);
+}
+
 #[test]
 fn test_partial_ord() {
     fn comp(lhs: &str, rhs: &str) -> Option<Ordering> {
-        StringParts::split(lhs).partial_cmp(&StringParts::split(rhs))
+        Some(natural_cmp(lhs, rhs))
     }
 
     assert_eq!(comp("asdf", "asdf"), Some(Ordering::Equal));
@@ -216,3 +279,146 @@ fn test_partial_ord() {
     assert_eq!(comp("1", "a"), Some(Ordering::Less));
     assert_eq!(comp("a", "1"), Some(Ordering::Greater));
 }
+
+#[test]
+fn test_prefix_boundary() {
+    let pairs = [
+        ("asdf", "asdf1"),
+        ("asd", "asdf"),
+        ("z10", "z10a"),
+        ("z10", "z100"),
+        ("x1y", "x1y2"),
+        ("", "0"),
+        ("", "a"),
+        ("a0", "a0b"),
+        ("1", "1a"),
+        ("1", "1.5"),
+    ];
+
+    for (short, long) in pairs {
+        let checks = [
+            natural_cmp(short, long),
+            natural_cmp_ci(short, long),
+            natural_cmp_chars(short.chars(), long.chars()),
+            natural_cmp_ascii(short, long),
+            NaturalOptions::new().compare(short, long),
+            Natural(short).cmp(&Natural(long)),
+        ];
+        assert!(
+            checks.iter().all(|ord| *ord == Ordering::Less),
+            "{} {}",
+            short,
+            long
+        );
+
+        assert_eq!(
+            natural_cmp(long, short),
+            Ordering::Greater,
+            "{} {}",
+            long,
+            short
+        );
+        assert!(Natural(short) < Natural(long));
+        assert!(Natural(long) > Natural(short));
+        assert!(Natural(short) != Natural(long));
+        assert_eq!(
+            Natural(long).partial_cmp(&Natural(short)),
+            Some(Ordering::Greater)
+        );
+    }
+
+    let mut list = vec!["asdf1", "z10a", "asdf", "z10", "asdf01", "z1"];
+    natural_sort(&mut list);
+    assert_eq!(list, vec!["asdf", "asdf1", "asdf01", "z1", "z10", "z10a"]);
+}
+
+#[test]
+fn test_stability() {
+    let input = vec!["a1", "b", "a01", "a001", "b", "a1", "a0001"];
+    let expected = vec!["a1", "a01", "a001", "a1", "a0001", "b", "b"];
+
+    let mut list = input.clone();
+    natural_sort(&mut list);
+    assert_eq!(list, expected);
+
+    let mut list = input.clone();
+    NaturalOptions::new().sort(&mut list);
+    assert_eq!(list, expected);
+
+    let mut list = input.clone();
+    NaturalSorter::new().sort(&mut list);
+    assert_eq!(list, expected);
+
+    let mut list = input.clone();
+    natural_sort_radix(&mut list);
+    assert_eq!(list, expected);
+
+    let mut list = input.clone();
+    natural_sort_keys(&mut list);
+    assert_eq!(list, expected);
+
+    let mut list = input.clone();
+    NaturalKeyCache::new(3).sort(&mut list);
+    assert_eq!(list, expected);
+
+    let list: Vec<&str> = input.iter().copied().natural_sorted().collect();
+    assert_eq!(list, expected);
+}
+
+#[test]
+fn test_deeply_segmented() {
+    let a = "a1".repeat(100_000);
+    let b = format!("{}2", "a1".repeat(99_999));
+
+    assert_eq!(natural_cmp(&a, &a), Ordering::Equal);
+    assert_eq!(natural_cmp(&a, &b), Ordering::Less);
+    assert_eq!(natural_cmp(&b, &a), Ordering::Greater);
+}
+
+#[test]
+fn test_total_order() {
+    let alphabet = [
+        "a",
+        "B",
+        "0",
+        "00",
+        "9",
+        "10",
+        "٣",
+        "½",
+        "",
+        "18446744073709551616",
+        "10a",
+    ];
+    let mut strings = vec![];
+    for x in alphabet {
+        for y in alphabet {
+            for z in alphabet {
+                strings.push(format!("{}{}{}", x, y, z));
+            }
+        }
+    }
+
+    for a in &strings {
+        for b in &strings {
+            assert_eq!(
+                natural_cmp(a, b),
+                natural_cmp(b, a).reverse(),
+                "{} {}",
+                a,
+                b
+            );
+        }
+    }
+
+    let mut sorted = strings.clone();
+    sorted.sort_by(|a, b| natural_cmp(a, b));
+    for pair in sorted.windows(2) {
+        assert_ne!(natural_cmp(&pair[0], &pair[1]), Ordering::Greater);
+    }
+    for (i, a) in sorted.iter().enumerate().step_by(97) {
+        for b in &sorted[i..] {
+            assert_ne!(natural_cmp(a, b), Ordering::Greater, "{} {}", a, b);
+        }
+    }
+}
@@ -0,0 +1,161 @@
+//! A port of the `natord` crate's `compare`, itself modelled on Martin Pool's `strnatcmp.c`
+
+use std::cmp::Ordering;
+use std::iter::Fuse;
+use std::str::Chars;
+
+/// One side of the comparison, holding the current character and its digit value
+struct Reader<'a> {
+    chars: Fuse<Chars<'a>>,
+    c: Option<char>,
+    digit: Option<u32>,
+}
+
+impl<'a> Reader<'a> {
+    fn new(s: &'a str) -> Self {
+        let mut reader = Reader {
+            chars: s.chars().fuse(),
+            c: None,
+            digit: None,
+        };
+        reader.read();
+        reader
+    }
+
+    fn read(&mut self) {
+        self.c = self.chars.next();
+        self.digit = self.c.and_then(|c| c.to_digit(10));
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.c.is_some_and(char::is_whitespace) {
+            self.read();
+        }
+    }
+}
+
+/// Compares like `natord::compare`
+///
+/// Whitespace is skipped entirely. A number starting with `0` on either side
+/// is compared digit by digit from the left, like a fraction, any other number
+/// by length and then digit by digit.
+pub(crate) fn natord(a: &str, b: &str) -> Ordering {
+    let mut l = Reader::new(a);
+    let mut r = Reader::new(b);
+
+    loop {
+        l.skip_whitespace();
+        r.skip_whitespace();
+
+        match (l.c, r.c) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(lc), Some(rc)) => match (l.digit, r.digit) {
+                (Some(ld), Some(rd)) => {
+                    let ord = match ld == 0 || rd == 0 {
+                        true => left_aligned(&mut l, &mut r, ld.cmp(&rd)),
+                        false => right_aligned(&mut l, &mut r, ld.cmp(&rd)),
+                    };
+                    if ord != Ordering::Equal {
+                        return ord;
+                    }
+                    continue;
+                }
+                _ => match lc.cmp(&rc) {
+                    Ordering::Equal => {}
+                    ord => return ord,
+                },
+            },
+        }
+
+        l.read();
+        r.read();
+    }
+}
+
+/// Compares two numbers digit by digit, the first one that differs decides
+fn left_aligned(l: &mut Reader, r: &mut Reader, first: Ordering) -> Ordering {
+    if first != Ordering::Equal {
+        return first;
+    }
+
+    loop {
+        l.read();
+        r.read();
+        match (l.digit, r.digit) {
+            (Some(ld), Some(rd)) if ld != rd => return ld.cmp(&rd),
+            (Some(_), Some(_)) => {}
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => return Ordering::Equal,
+        }
+    }
+}
+
+/// Compares two numbers by length, then by the first digit that differs
+fn right_aligned(l: &mut Reader, r: &mut Reader, first: Ordering) -> Ordering {
+    let mut ord = first;
+
+    loop {
+        l.read();
+        r.read();
+        match (l.digit, r.digit) {
+            (Some(ld), Some(rd)) => {
+                if ord == Ordering::Equal {
+                    ord = ld.cmp(&rd);
+                }
+            }
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => return ord,
+        }
+    }
+}
+
+#[test]
+fn test_natord() {
+    let corpus = [
+        "1-02",
+        "1-2",
+        "1-20",
+        "10-20",
+        "fred",
+        "jane",
+        "pic1",
+        "pic2",
+        "pic2a",
+        "pic3",
+        "pic4",
+        "pic4   alpha",
+        "pic 4 else",
+        "pic4  last",
+        "pic5",
+        "pic5.07",
+        "pic5.08",
+        "pic5.13",
+        "pic5.113",
+        "pic 5 something",
+        "pic 6",
+        "pic   7",
+        "pic100",
+        "pic100a",
+        "pic120",
+        "pic121",
+        "pic2000",
+        "tom",
+        "x2-g8",
+        "x2-y7",
+        "x2-y8",
+        "x8-y8",
+    ];
+
+    for (i, a) in corpus.iter().enumerate() {
+        for (j, b) in corpus.iter().enumerate() {
+            assert_eq!(natord(a, b), i.cmp(&j), "{:?} {:?}", a, b);
+        }
+    }
+
+    assert_eq!(natord("1.001", "1.02"), Ordering::Less);
+    assert_eq!(natord("a 1", "a1"), Ordering::Equal);
+}
@@ -0,0 +1,258 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+use crate::{natural_cmp, natural_eq_signature};
+
+/// Wraps a string-like value so that it orders naturally
+///
+/// Equality and ordering both go through [`natural_cmp`], which makes the
+/// wrapper usable as a key in ordered collections like `BTreeMap`.
+/// Note that strings which only differ in zero padding (`"a01"` and `"a1"`)
+/// are equal, and hash the same through [`natural_eq_signature`].
+///
+/// # Examples
+/// ```
+/// use natural_sort::Natural;
+/// let mut list = vec![Natural("z10"), Natural("z9"), Natural("z101")];
+/// list.sort();
+/// assert_eq!(list, vec![Natural("z9"), Natural("z10"), Natural("z101")]);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Natural<T>(pub T);
+
+/// An owned, naturally ordered string
+///
+/// # Examples
+/// ```
+/// use std::collections::BTreeMap;
+/// use natural_sort::NaturalString;
+/// let mut map = BTreeMap::new();
+/// map.insert(NaturalString::from("step10"), 3);
+/// map.insert(NaturalString::from("step2"), 2);
+/// map.insert(NaturalString::from("step1"), 1);
+/// let keys: Vec<&str> = map.keys().map(|k| k.as_str()).collect();
+/// assert_eq!(keys, vec!["step1", "step2", "step10"]);
+/// ```
+pub type NaturalString = Natural<String>;
+
+/// A map that iterates its keys in natural order
+pub type NaturalMap<V> = BTreeMap<NaturalString, V>;
+
+/// A set that iterates in natural order
+pub type NaturalSet = BTreeSet<NaturalString>;
+
+/// Collects key value pairs into a [`NaturalMap`]
+///
+/// Keys that compare naturally equal (`"a01"` and `"a1"`) collapse into one
+/// entry, which keeps both the key text and the value of the last pair.
+///
+/// # Arguments
+///
+/// * `vals` - Anything that iterates over `(key, value)` pairs with string-like keys
+///
+/// # Examples
+/// ```
+/// use natural_sort::natural_map;
+/// let map = natural_map(vec![("step10", 3), ("step2", 2), ("step1", 1)]);
+/// let keys: Vec<&str> = map.keys().map(|k| k.as_str()).collect();
+/// assert_eq!(keys, vec!["step1", "step2", "step10"]);
+/// ```
+pub fn natural_map<K, V, I>(vals: I) -> NaturalMap<V>
+where
+    K: Into<String>,
+    I: IntoIterator<Item = (K, V)>,
+{
+    let mut map = NaturalMap::new();
+    for (k, v) in vals {
+        let key = Natural(k.into());
+        map.remove(&key);
+        map.insert(key, v);
+    }
+    map
+}
+
+/// Collects strings into a [`NaturalSet`]
+///
+/// Of strings that compare naturally equal only the last one is kept.
+///
+/// # Arguments
+///
+/// * `vals` - Anything that iterates over string-like values
+///
+/// # Examples
+/// ```
+/// use natural_sort::natural_set;
+/// let set = natural_set(vec!["z10", "z9", "z101"]);
+/// let sorted: Vec<&str> = set.iter().map(|s| s.as_str()).collect();
+/// assert_eq!(sorted, vec!["z9", "z10", "z101"]);
+/// ```
+pub fn natural_set<K, I>(vals: I) -> NaturalSet
+where
+    K: Into<String>,
+    I: IntoIterator<Item = K>,
+{
+    let mut set = NaturalSet::new();
+    for k in vals {
+        set.replace(Natural(k.into()));
+    }
+    set
+}
+
+impl<T> Natural<T> {
+    /// Unwraps the inner value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl Natural<String> {
+    /// Returns the wrapped string as a slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<T> From<T> for Natural<T> {
+    fn from(value: T) -> Self {
+        Natural(value)
+    }
+}
+
+impl From<&str> for Natural<String> {
+    fn from(value: &str) -> Self {
+        Natural(String::from(value))
+    }
+}
+
+impl<T> Deref for Natural<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: AsRef<str>> AsRef<str> for Natural<T> {
+    fn as_ref(&self) -> &str {
+        self.0.as_ref()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Natural<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: AsRef<str>> PartialEq for Natural<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: AsRef<str>> Eq for Natural<T> {}
+
+impl<T: AsRef<str>> Hash for Natural<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        natural_eq_signature(self.0.as_ref()).hash(state)
+    }
+}
+
+impl<T: AsRef<str>> PartialOrd for Natural<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: AsRef<str>> Ord for Natural<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        natural_cmp(self.0.as_ref(), other.0.as_ref())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Natural<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Natural<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Natural)
+    }
+}
+
+#[test]
+fn test_natural_ord() {
+    let mut list: Vec<NaturalString> = vec!["z10a", "b23g", "z999", "z10", "z9"]
+        .into_iter()
+        .map(NaturalString::from)
+        .collect();
+    list.sort();
+
+    let sorted: Vec<&str> = list.iter().map(|s| s.as_str()).collect();
+    assert_eq!(sorted, vec!["b23g", "z9", "z10", "z10a", "z999"]);
+
+    assert_eq!(Natural("a01"), Natural("a1"));
+    assert!(Natural("a2") < Natural("a10"));
+}
+
+#[test]
+fn test_natural_collections() {
+    let map = natural_map(vec![
+        ("z10".to_string(), 'a'),
+        ("z9".to_string(), 'b'),
+        ("z09".to_string(), 'c'),
+    ]);
+    let entries: Vec<(&str, char)> = map.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+    assert_eq!(entries, vec![("z09", 'c'), ("z10", 'a')]);
+    assert_eq!(map.get(&NaturalString::from("z10")), Some(&'a'));
+
+    // the key text of the last equal pair survives, not just its value
+    let map = natural_map([("a01", 1), ("a1", 2), ("a001", 3)]);
+    let entries: Vec<(&str, i32)> = map.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+    assert_eq!(entries, vec![("a001", 3)]);
+    let map = natural_map([("a1", 1), ("b", 2), ("a01", 3)]);
+    assert_eq!(map.keys().next().unwrap().as_str(), "a01");
+
+    let set = natural_set(["x12z101", "x12z34", "b23g"]);
+    assert!(set.contains(&NaturalString::from("b23g")));
+    let sorted: Vec<&str> = set.iter().map(|s| s.as_str()).collect();
+    assert_eq!(sorted, vec!["b23g", "x12z34", "x12z101"]);
+
+    let set = natural_set(["a1", "b", "a01"]);
+    let sorted: Vec<&str> = set.iter().map(|s| s.as_str()).collect();
+    assert_eq!(sorted, vec!["a01", "b"]);
+}
+
+#[test]
+fn test_natural_hash() {
+    use std::collections::HashSet;
+
+    let set: HashSet<Natural<&str>> = ["host007", "host7", "host07", "host70"]
+        .into_iter()
+        .map(Natural)
+        .collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&Natural("host0007")));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_round_trip() {
+    use std::collections::BTreeMap;
+
+    let json = r#"{"z10":3,"z2":2,"z1":1}"#;
+    let map: BTreeMap<NaturalString, u32> = serde_json::from_str(json).unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&map).unwrap(),
+        r#"{"z1":1,"z2":2,"z10":3}"#
+    );
+    assert_eq!(serde_json::to_string(&Natural("z10")).unwrap(), r#""z10""#);
+}
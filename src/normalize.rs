@@ -0,0 +1,59 @@
+use crate::{numeric, segments, Segment};
+
+/// Rewrites every number with exactly `width` digits, or more if it needs them
+///
+/// Leading zeros are dropped first, the same way the comparison ignores them,
+/// so normalizing never changes how names compare with [`natural_cmp`](crate::natural_cmp)
+/// and names that differ only in leading zeros normalize to the same string.
+/// Padding uses the zero of the number's own script, so `"٣"` becomes `"٠٣"`.
+///
+/// # Arguments
+///
+/// * `s` - The string to normalize
+/// * `width` - The number of digits every number is padded to
+///
+/// # Examples
+/// ```
+/// use natural_sort::normalize_numbers;
+/// assert_eq!(normalize_numbers("img7.png", 3), "img007.png");
+/// assert_eq!(normalize_numbers("img0007.png", 3), "img007.png");
+/// assert_eq!(normalize_numbers("img1234.png", 3), "img1234.png");
+/// ```
+pub fn normalize_numbers(s: &str, width: usize) -> String {
+    let mut out = String::with_capacity(s.len() + width);
+
+    for segment in segments(s) {
+        match segment {
+            Segment::Alpha(text) => out.push_str(text),
+            Segment::Number { raw, .. } => {
+                let digits = numeric::trim_zeros(raw);
+                let zero = raw.chars().next().and_then(numeric::zero_of).unwrap_or('0');
+                let padding = width.saturating_sub(digits.chars().count());
+                out.extend(std::iter::repeat_n(zero, padding));
+                out.push_str(digits);
+            }
+        }
+    }
+
+    out
+}
+
+#[test]
+fn test_normalize_numbers() {
+    assert_eq!(normalize_numbers("", 3), "");
+    assert_eq!(normalize_numbers("abc", 3), "abc");
+    assert_eq!(normalize_numbers("0", 3), "000");
+    assert_eq!(normalize_numbers("000", 0), "");
+    assert_eq!(normalize_numbers("s1e02 ٣", 2), "s01e02 ٠٣");
+    assert_eq!(normalize_numbers("x٠٠٧", 2), "x٠٧");
+    assert_eq!(normalize_numbers("٠٠", 3), "٠٠٠");
+
+    for s in ["img7.png", "img007.png", "v1.2.10", "x12z101", "a٠٣b٤٥"] {
+        let normalized = normalize_numbers(s, 4);
+        assert_eq!(
+            crate::natural_cmp(s, &normalized),
+            std::cmp::Ordering::Equal
+        );
+        assert_eq!(normalize_numbers(&normalized, 4), normalized);
+    }
+}
@@ -0,0 +1,289 @@
+//! Numeric values of the characters that are numeric but not decimal digits,
+//! and the decimal digits of every script
+//!
+//! Covers the `Nl` and `No` general categories, like `'½'`, `'²'`, `'③'` or
+//! `'Ⅻ'`, and the `Nd` category, like `'٣'`, as of Unicode 14.0.0. Characters
+//! added later are text until the tables are regenerated, whatever the
+//! standard library thinks of them.
+
+use std::cmp::Ordering;
+
+/// Runs of characters as `(first, len, numerator, denominator)`, where each
+/// character of a run is worth one more than the one before
+#[rustfmt::skip]
+const VALUES: [(u32, u16, i64, u16); 638] = [
+    (0x00b2, 2, 2, 1), (0x00b9, 1, 1, 1), (0x00bc, 1, 1, 4), (0x00bd, 1, 1, 2), (0x00be, 1, 3, 4),
+    (0x09f4, 1, 1, 16), (0x09f5, 1, 1, 8), (0x09f6, 1, 3, 16), (0x09f7, 1, 1, 4), (0x09f8, 1, 3, 4),
+    (0x09f9, 1, 16, 1), (0x0b72, 1, 1, 4), (0x0b73, 1, 1, 2), (0x0b74, 1, 3, 4), (0x0b75, 1, 1, 16),
+    (0x0b76, 1, 1, 8), (0x0b77, 1, 3, 16), (0x0bf0, 1, 10, 1), (0x0bf1, 1, 100, 1),
+    (0x0bf2, 1, 1000, 1), (0x0c78, 4, 0, 1), (0x0c7c, 3, 1, 1), (0x0d58, 1, 1, 160),
+    (0x0d59, 1, 1, 40), (0x0d5a, 1, 3, 80), (0x0d5b, 1, 1, 20), (0x0d5c, 1, 1, 10),
+    (0x0d5d, 1, 3, 20), (0x0d5e, 1, 1, 5), (0x0d70, 1, 10, 1), (0x0d71, 1, 100, 1),
+    (0x0d72, 1, 1000, 1), (0x0d73, 1, 1, 4), (0x0d74, 1, 1, 2), (0x0d75, 1, 3, 4),
+    (0x0d76, 1, 1, 16), (0x0d77, 1, 1, 8), (0x0d78, 1, 3, 16), (0x0f2a, 1, 1, 2), (0x0f2b, 1, 3, 2),
+    (0x0f2c, 1, 5, 2), (0x0f2d, 1, 7, 2), (0x0f2e, 1, 9, 2), (0x0f2f, 1, 11, 2), (0x0f30, 1, 13, 2),
+    (0x0f31, 1, 15, 2), (0x0f32, 1, 17, 2), (0x0f33, 1, -1, 2), (0x1369, 10, 1, 1),
+    (0x1373, 1, 20, 1), (0x1374, 1, 30, 1), (0x1375, 1, 40, 1), (0x1376, 1, 50, 1),
+    (0x1377, 1, 60, 1), (0x1378, 1, 70, 1), (0x1379, 1, 80, 1), (0x137a, 1, 90, 1),
+    (0x137b, 1, 100, 1), (0x137c, 1, 10000, 1), (0x16ee, 3, 17, 1), (0x17f0, 10, 0, 1),
+    (0x19da, 1, 1, 1), (0x2070, 1, 0, 1), (0x2074, 6, 4, 1), (0x2080, 10, 0, 1), (0x2150, 1, 1, 7),
+    (0x2151, 1, 1, 9), (0x2152, 1, 1, 10), (0x2153, 1, 1, 3), (0x2154, 1, 2, 3), (0x2155, 1, 1, 5),
+    (0x2156, 1, 2, 5), (0x2157, 1, 3, 5), (0x2158, 1, 4, 5), (0x2159, 1, 1, 6), (0x215a, 1, 5, 6),
+    (0x215b, 1, 1, 8), (0x215c, 1, 3, 8), (0x215d, 1, 5, 8), (0x215e, 1, 7, 8), (0x215f, 1, 1, 1),
+    (0x2160, 12, 1, 1), (0x216c, 1, 50, 1), (0x216d, 1, 100, 1), (0x216e, 1, 500, 1),
+    (0x216f, 1, 1000, 1), (0x2170, 12, 1, 1), (0x217c, 1, 50, 1), (0x217d, 1, 100, 1),
+    (0x217e, 1, 500, 1), (0x217f, 1, 1000, 1), (0x2180, 1, 1000, 1), (0x2181, 1, 5000, 1),
+    (0x2182, 1, 10000, 1), (0x2185, 1, 6, 1), (0x2186, 1, 50, 1), (0x2187, 1, 50000, 1),
+    (0x2188, 1, 100000, 1), (0x2189, 1, 0, 1), (0x2460, 20, 1, 1), (0x2474, 20, 1, 1),
+    (0x2488, 20, 1, 1), (0x24ea, 1, 0, 1), (0x24eb, 10, 11, 1), (0x24f5, 10, 1, 1),
+    (0x24ff, 1, 0, 1), (0x2776, 10, 1, 1), (0x2780, 10, 1, 1), (0x278a, 10, 1, 1),
+    (0x2cfd, 1, 1, 2), (0x3007, 1, 0, 1), (0x3021, 9, 1, 1), (0x3038, 1, 10, 1), (0x3039, 1, 20, 1),
+    (0x303a, 1, 30, 1), (0x3192, 4, 1, 1), (0x3220, 10, 1, 1), (0x3248, 1, 10, 1),
+    (0x3249, 1, 20, 1), (0x324a, 1, 30, 1), (0x324b, 1, 40, 1), (0x324c, 1, 50, 1),
+    (0x324d, 1, 60, 1), (0x324e, 1, 70, 1), (0x324f, 1, 80, 1), (0x3251, 15, 21, 1),
+    (0x3280, 10, 1, 1), (0x32b1, 15, 36, 1), (0xa6e6, 9, 1, 1), (0xa6ef, 1, 0, 1),
+    (0xa830, 1, 1, 4), (0xa831, 1, 1, 2), (0xa832, 1, 3, 4), (0xa833, 1, 1, 16), (0xa834, 1, 1, 8),
+    (0xa835, 1, 3, 16), (0x10107, 10, 1, 1), (0x10111, 1, 20, 1), (0x10112, 1, 30, 1),
+    (0x10113, 1, 40, 1), (0x10114, 1, 50, 1), (0x10115, 1, 60, 1), (0x10116, 1, 70, 1),
+    (0x10117, 1, 80, 1), (0x10118, 1, 90, 1), (0x10119, 1, 100, 1), (0x1011a, 1, 200, 1),
+    (0x1011b, 1, 300, 1), (0x1011c, 1, 400, 1), (0x1011d, 1, 500, 1), (0x1011e, 1, 600, 1),
+    (0x1011f, 1, 700, 1), (0x10120, 1, 800, 1), (0x10121, 1, 900, 1), (0x10122, 1, 1000, 1),
+    (0x10123, 1, 2000, 1), (0x10124, 1, 3000, 1), (0x10125, 1, 4000, 1), (0x10126, 1, 5000, 1),
+    (0x10127, 1, 6000, 1), (0x10128, 1, 7000, 1), (0x10129, 1, 8000, 1), (0x1012a, 1, 9000, 1),
+    (0x1012b, 1, 10000, 1), (0x1012c, 1, 20000, 1), (0x1012d, 1, 30000, 1), (0x1012e, 1, 40000, 1),
+    (0x1012f, 1, 50000, 1), (0x10130, 1, 60000, 1), (0x10131, 1, 70000, 1), (0x10132, 1, 80000, 1),
+    (0x10133, 1, 90000, 1), (0x10140, 1, 1, 4), (0x10141, 1, 1, 2), (0x10142, 1, 1, 1),
+    (0x10143, 1, 5, 1), (0x10144, 1, 50, 1), (0x10145, 1, 500, 1), (0x10146, 1, 5000, 1),
+    (0x10147, 1, 50000, 1), (0x10148, 1, 5, 1), (0x10149, 1, 10, 1), (0x1014a, 1, 50, 1),
+    (0x1014b, 1, 100, 1), (0x1014c, 1, 500, 1), (0x1014d, 1, 1000, 1), (0x1014e, 1, 5000, 1),
+    (0x1014f, 1, 5, 1), (0x10150, 1, 10, 1), (0x10151, 1, 50, 1), (0x10152, 1, 100, 1),
+    (0x10153, 1, 500, 1), (0x10154, 1, 1000, 1), (0x10155, 1, 10000, 1), (0x10156, 1, 50000, 1),
+    (0x10157, 1, 10, 1), (0x10158, 1, 1, 1), (0x10159, 1, 1, 1), (0x1015a, 2, 1, 1),
+    (0x1015c, 1, 2, 1), (0x1015d, 1, 2, 1), (0x1015e, 1, 2, 1), (0x1015f, 1, 5, 1),
+    (0x10160, 1, 10, 1), (0x10161, 1, 10, 1), (0x10162, 1, 10, 1), (0x10163, 1, 10, 1),
+    (0x10164, 1, 10, 1), (0x10165, 1, 30, 1), (0x10166, 1, 50, 1), (0x10167, 1, 50, 1),
+    (0x10168, 1, 50, 1), (0x10169, 1, 50, 1), (0x1016a, 1, 100, 1), (0x1016b, 1, 300, 1),
+    (0x1016c, 1, 500, 1), (0x1016d, 1, 500, 1), (0x1016e, 1, 500, 1), (0x1016f, 1, 500, 1),
+    (0x10170, 1, 500, 1), (0x10171, 1, 1000, 1), (0x10172, 1, 5000, 1), (0x10173, 1, 5, 1),
+    (0x10174, 1, 50, 1), (0x10175, 1, 1, 2), (0x10176, 1, 1, 2), (0x10177, 1, 2, 3),
+    (0x10178, 1, 3, 4), (0x1018a, 1, 0, 1), (0x1018b, 1, 1, 4), (0x102e1, 10, 1, 1),
+    (0x102eb, 1, 20, 1), (0x102ec, 1, 30, 1), (0x102ed, 1, 40, 1), (0x102ee, 1, 50, 1),
+    (0x102ef, 1, 60, 1), (0x102f0, 1, 70, 1), (0x102f1, 1, 80, 1), (0x102f2, 1, 90, 1),
+    (0x102f3, 1, 100, 1), (0x102f4, 1, 200, 1), (0x102f5, 1, 300, 1), (0x102f6, 1, 400, 1),
+    (0x102f7, 1, 500, 1), (0x102f8, 1, 600, 1), (0x102f9, 1, 700, 1), (0x102fa, 1, 800, 1),
+    (0x102fb, 1, 900, 1), (0x10320, 1, 1, 1), (0x10321, 1, 5, 1), (0x10322, 1, 10, 1),
+    (0x10323, 1, 50, 1), (0x10341, 1, 90, 1), (0x1034a, 1, 900, 1), (0x103d1, 2, 1, 1),
+    (0x103d3, 1, 10, 1), (0x103d4, 1, 20, 1), (0x103d5, 1, 100, 1), (0x10858, 3, 1, 1),
+    (0x1085b, 1, 10, 1), (0x1085c, 1, 20, 1), (0x1085d, 1, 100, 1), (0x1085e, 1, 1000, 1),
+    (0x1085f, 1, 10000, 1), (0x10879, 5, 1, 1), (0x1087e, 1, 10, 1), (0x1087f, 1, 20, 1),
+    (0x108a7, 4, 1, 1), (0x108ab, 2, 4, 1), (0x108ad, 1, 10, 1), (0x108ae, 1, 20, 1),
+    (0x108af, 1, 100, 1), (0x108fb, 1, 1, 1), (0x108fc, 1, 5, 1), (0x108fd, 1, 10, 1),
+    (0x108fe, 1, 20, 1), (0x108ff, 1, 100, 1), (0x10916, 1, 1, 1), (0x10917, 1, 10, 1),
+    (0x10918, 1, 20, 1), (0x10919, 1, 100, 1), (0x1091a, 2, 2, 1), (0x109bc, 1, 11, 12),
+    (0x109bd, 1, 1, 2), (0x109c0, 10, 1, 1), (0x109ca, 1, 20, 1), (0x109cb, 1, 30, 1),
+    (0x109cc, 1, 40, 1), (0x109cd, 1, 50, 1), (0x109ce, 1, 60, 1), (0x109cf, 1, 70, 1),
+    (0x109d2, 1, 100, 1), (0x109d3, 1, 200, 1), (0x109d4, 1, 300, 1), (0x109d5, 1, 400, 1),
+    (0x109d6, 1, 500, 1), (0x109d7, 1, 600, 1), (0x109d8, 1, 700, 1), (0x109d9, 1, 800, 1),
+    (0x109da, 1, 900, 1), (0x109db, 1, 1000, 1), (0x109dc, 1, 2000, 1), (0x109dd, 1, 3000, 1),
+    (0x109de, 1, 4000, 1), (0x109df, 1, 5000, 1), (0x109e0, 1, 6000, 1), (0x109e1, 1, 7000, 1),
+    (0x109e2, 1, 8000, 1), (0x109e3, 1, 9000, 1), (0x109e4, 1, 10000, 1), (0x109e5, 1, 20000, 1),
+    (0x109e6, 1, 30000, 1), (0x109e7, 1, 40000, 1), (0x109e8, 1, 50000, 1), (0x109e9, 1, 60000, 1),
+    (0x109ea, 1, 70000, 1), (0x109eb, 1, 80000, 1), (0x109ec, 1, 90000, 1), (0x109ed, 1, 100000, 1),
+    (0x109ee, 1, 200000, 1), (0x109ef, 1, 300000, 1), (0x109f0, 1, 400000, 1),
+    (0x109f1, 1, 500000, 1), (0x109f2, 1, 600000, 1), (0x109f3, 1, 700000, 1),
+    (0x109f4, 1, 800000, 1), (0x109f5, 1, 900000, 1), (0x109f6, 1, 1, 12), (0x109f7, 1, 1, 6),
+    (0x109f8, 1, 1, 4), (0x109f9, 1, 1, 3), (0x109fa, 1, 5, 12), (0x109fb, 1, 1, 2),
+    (0x109fc, 1, 7, 12), (0x109fd, 1, 2, 3), (0x109fe, 1, 3, 4), (0x109ff, 1, 5, 6),
+    (0x10a40, 4, 1, 1), (0x10a44, 1, 10, 1), (0x10a45, 1, 20, 1), (0x10a46, 1, 100, 1),
+    (0x10a47, 1, 1000, 1), (0x10a48, 1, 1, 2), (0x10a7d, 1, 1, 1), (0x10a7e, 1, 50, 1),
+    (0x10a9d, 1, 1, 1), (0x10a9e, 1, 10, 1), (0x10a9f, 1, 20, 1), (0x10aeb, 1, 1, 1),
+    (0x10aec, 1, 5, 1), (0x10aed, 1, 10, 1), (0x10aee, 1, 20, 1), (0x10aef, 1, 100, 1),
+    (0x10b58, 4, 1, 1), (0x10b5c, 1, 10, 1), (0x10b5d, 1, 20, 1), (0x10b5e, 1, 100, 1),
+    (0x10b5f, 1, 1000, 1), (0x10b78, 4, 1, 1), (0x10b7c, 1, 10, 1), (0x10b7d, 1, 20, 1),
+    (0x10b7e, 1, 100, 1), (0x10b7f, 1, 1000, 1), (0x10ba9, 4, 1, 1), (0x10bad, 1, 10, 1),
+    (0x10bae, 1, 20, 1), (0x10baf, 1, 100, 1), (0x10cfa, 1, 1, 1), (0x10cfb, 1, 5, 1),
+    (0x10cfc, 1, 10, 1), (0x10cfd, 1, 50, 1), (0x10cfe, 1, 100, 1), (0x10cff, 1, 1000, 1),
+    (0x10e60, 10, 1, 1), (0x10e6a, 1, 20, 1), (0x10e6b, 1, 30, 1), (0x10e6c, 1, 40, 1),
+    (0x10e6d, 1, 50, 1), (0x10e6e, 1, 60, 1), (0x10e6f, 1, 70, 1), (0x10e70, 1, 80, 1),
+    (0x10e71, 1, 90, 1), (0x10e72, 1, 100, 1), (0x10e73, 1, 200, 1), (0x10e74, 1, 300, 1),
+    (0x10e75, 1, 400, 1), (0x10e76, 1, 500, 1), (0x10e77, 1, 600, 1), (0x10e78, 1, 700, 1),
+    (0x10e79, 1, 800, 1), (0x10e7a, 1, 900, 1), (0x10e7b, 1, 1, 2), (0x10e7c, 1, 1, 4),
+    (0x10e7d, 1, 1, 3), (0x10e7e, 1, 2, 3), (0x10f1d, 5, 1, 1), (0x10f22, 1, 10, 1),
+    (0x10f23, 1, 20, 1), (0x10f24, 1, 30, 1), (0x10f25, 1, 100, 1), (0x10f26, 1, 1, 2),
+    (0x10f51, 1, 1, 1), (0x10f52, 1, 10, 1), (0x10f53, 1, 20, 1), (0x10f54, 1, 100, 1),
+    (0x10fc5, 4, 1, 1), (0x10fc9, 1, 10, 1), (0x10fca, 1, 20, 1), (0x10fcb, 1, 100, 1),
+    (0x11052, 10, 1, 1), (0x1105c, 1, 20, 1), (0x1105d, 1, 30, 1), (0x1105e, 1, 40, 1),
+    (0x1105f, 1, 50, 1), (0x11060, 1, 60, 1), (0x11061, 1, 70, 1), (0x11062, 1, 80, 1),
+    (0x11063, 1, 90, 1), (0x11064, 1, 100, 1), (0x11065, 1, 1000, 1), (0x111e1, 10, 1, 1),
+    (0x111eb, 1, 20, 1), (0x111ec, 1, 30, 1), (0x111ed, 1, 40, 1), (0x111ee, 1, 50, 1),
+    (0x111ef, 1, 60, 1), (0x111f0, 1, 70, 1), (0x111f1, 1, 80, 1), (0x111f2, 1, 90, 1),
+    (0x111f3, 1, 100, 1), (0x111f4, 1, 1000, 1), (0x1173a, 1, 10, 1), (0x1173b, 1, 20, 1),
+    (0x118ea, 1, 10, 1), (0x118eb, 1, 20, 1), (0x118ec, 1, 30, 1), (0x118ed, 1, 40, 1),
+    (0x118ee, 1, 50, 1), (0x118ef, 1, 60, 1), (0x118f0, 1, 70, 1), (0x118f1, 1, 80, 1),
+    (0x118f2, 1, 90, 1), (0x11c5a, 10, 1, 1), (0x11c64, 1, 20, 1), (0x11c65, 1, 30, 1),
+    (0x11c66, 1, 40, 1), (0x11c67, 1, 50, 1), (0x11c68, 1, 60, 1), (0x11c69, 1, 70, 1),
+    (0x11c6a, 1, 80, 1), (0x11c6b, 1, 90, 1), (0x11c6c, 1, 100, 1), (0x11fc0, 1, 1, 320),
+    (0x11fc1, 1, 1, 160), (0x11fc2, 1, 1, 80), (0x11fc3, 1, 1, 64), (0x11fc4, 1, 1, 40),
+    (0x11fc5, 1, 1, 32), (0x11fc6, 1, 3, 80), (0x11fc7, 1, 3, 64), (0x11fc8, 1, 1, 20),
+    (0x11fc9, 1, 1, 16), (0x11fca, 1, 1, 16), (0x11fcb, 1, 1, 10), (0x11fcc, 1, 1, 8),
+    (0x11fcd, 1, 3, 20), (0x11fce, 1, 3, 16), (0x11fcf, 1, 1, 5), (0x11fd0, 1, 1, 4),
+    (0x11fd1, 1, 1, 2), (0x11fd2, 1, 1, 2), (0x11fd3, 1, 3, 4), (0x11fd4, 1, 1, 320),
+    (0x12400, 8, 2, 1), (0x12408, 7, 3, 1), (0x1240f, 6, 4, 1), (0x12415, 9, 1, 1),
+    (0x1241e, 5, 1, 1), (0x12423, 2, 2, 1), (0x12425, 7, 3, 1), (0x1242c, 3, 1, 1),
+    (0x1242f, 3, 3, 1), (0x12432, 1, 216000, 1), (0x12433, 1, 432000, 1), (0x12434, 3, 1, 1),
+    (0x12437, 3, 3, 1), (0x1243a, 1, 3, 1), (0x1243b, 2, 3, 1), (0x1243d, 1, 4, 1),
+    (0x1243e, 1, 4, 1), (0x1243f, 1, 4, 1), (0x12440, 2, 6, 1), (0x12442, 1, 7, 1),
+    (0x12443, 2, 7, 1), (0x12445, 2, 8, 1), (0x12447, 1, 9, 1), (0x12448, 1, 9, 1),
+    (0x12449, 1, 9, 1), (0x1244a, 5, 2, 1), (0x1244f, 4, 1, 1), (0x12453, 2, 4, 1),
+    (0x12455, 1, 5, 1), (0x12456, 2, 2, 1), (0x12458, 2, 1, 1), (0x1245a, 1, 1, 3),
+    (0x1245b, 1, 2, 3), (0x1245c, 1, 5, 6), (0x1245d, 1, 1, 3), (0x1245e, 1, 2, 3),
+    (0x1245f, 1, 1, 8), (0x12460, 1, 1, 4), (0x12461, 1, 1, 6), (0x12462, 1, 1, 4),
+    (0x12463, 1, 1, 4), (0x12464, 1, 1, 2), (0x12465, 1, 1, 3), (0x12466, 1, 2, 3),
+    (0x12467, 1, 40, 1), (0x12468, 1, 50, 1), (0x12469, 6, 4, 1), (0x16b5b, 1, 10, 1),
+    (0x16b5c, 1, 100, 1), (0x16b5d, 1, 10000, 1), (0x16b5e, 1, 1000000, 1),
+    (0x16b5f, 1, 100000000, 1), (0x16b60, 1, 10000000000, 1), (0x16b61, 1, 1000000000000, 1),
+    (0x16e80, 20, 0, 1), (0x16e94, 3, 1, 1), (0x1d2e0, 20, 0, 1), (0x1d360, 10, 1, 1),
+    (0x1d36a, 1, 20, 1), (0x1d36b, 1, 30, 1), (0x1d36c, 1, 40, 1), (0x1d36d, 1, 50, 1),
+    (0x1d36e, 1, 60, 1), (0x1d36f, 1, 70, 1), (0x1d370, 1, 80, 1), (0x1d371, 1, 90, 1),
+    (0x1d372, 5, 1, 1), (0x1d377, 1, 1, 1), (0x1d378, 1, 5, 1), (0x1e8c7, 9, 1, 1),
+    (0x1ec71, 10, 1, 1), (0x1ec7b, 1, 20, 1), (0x1ec7c, 1, 30, 1), (0x1ec7d, 1, 40, 1),
+    (0x1ec7e, 1, 50, 1), (0x1ec7f, 1, 60, 1), (0x1ec80, 1, 70, 1), (0x1ec81, 1, 80, 1),
+    (0x1ec82, 1, 90, 1), (0x1ec83, 1, 100, 1), (0x1ec84, 1, 200, 1), (0x1ec85, 1, 300, 1),
+    (0x1ec86, 1, 400, 1), (0x1ec87, 1, 500, 1), (0x1ec88, 1, 600, 1), (0x1ec89, 1, 700, 1),
+    (0x1ec8a, 1, 800, 1), (0x1ec8b, 1, 900, 1), (0x1ec8c, 1, 1000, 1), (0x1ec8d, 1, 2000, 1),
+    (0x1ec8e, 1, 3000, 1), (0x1ec8f, 1, 4000, 1), (0x1ec90, 1, 5000, 1), (0x1ec91, 1, 6000, 1),
+    (0x1ec92, 1, 7000, 1), (0x1ec93, 1, 8000, 1), (0x1ec94, 1, 9000, 1), (0x1ec95, 1, 10000, 1),
+    (0x1ec96, 1, 20000, 1), (0x1ec97, 1, 30000, 1), (0x1ec98, 1, 40000, 1), (0x1ec99, 1, 50000, 1),
+    (0x1ec9a, 1, 60000, 1), (0x1ec9b, 1, 70000, 1), (0x1ec9c, 1, 80000, 1), (0x1ec9d, 1, 90000, 1),
+    (0x1ec9e, 1, 100000, 1), (0x1ec9f, 1, 200000, 1), (0x1eca0, 1, 100000, 1),
+    (0x1eca1, 1, 10000000, 1), (0x1eca2, 1, 20000000, 1), (0x1eca3, 9, 1, 1), (0x1ecad, 1, 1, 4),
+    (0x1ecae, 1, 1, 2), (0x1ecaf, 1, 3, 4), (0x1ecb1, 2, 1, 1), (0x1ecb3, 1, 10000, 1),
+    (0x1ecb4, 1, 100000, 1), (0x1ed01, 10, 1, 1), (0x1ed0b, 1, 20, 1), (0x1ed0c, 1, 30, 1),
+    (0x1ed0d, 1, 40, 1), (0x1ed0e, 1, 50, 1), (0x1ed0f, 1, 60, 1), (0x1ed10, 1, 70, 1),
+    (0x1ed11, 1, 80, 1), (0x1ed12, 1, 90, 1), (0x1ed13, 1, 100, 1), (0x1ed14, 1, 200, 1),
+    (0x1ed15, 1, 300, 1), (0x1ed16, 1, 400, 1), (0x1ed17, 1, 500, 1), (0x1ed18, 1, 600, 1),
+    (0x1ed19, 1, 700, 1), (0x1ed1a, 1, 800, 1), (0x1ed1b, 1, 900, 1), (0x1ed1c, 1, 1000, 1),
+    (0x1ed1d, 1, 2000, 1), (0x1ed1e, 1, 3000, 1), (0x1ed1f, 1, 4000, 1), (0x1ed20, 1, 5000, 1),
+    (0x1ed21, 1, 6000, 1), (0x1ed22, 1, 7000, 1), (0x1ed23, 1, 8000, 1), (0x1ed24, 1, 9000, 1),
+    (0x1ed25, 1, 10000, 1), (0x1ed26, 1, 20000, 1), (0x1ed27, 1, 30000, 1), (0x1ed28, 1, 40000, 1),
+    (0x1ed29, 1, 50000, 1), (0x1ed2a, 1, 60000, 1), (0x1ed2b, 1, 70000, 1), (0x1ed2c, 1, 80000, 1),
+    (0x1ed2d, 1, 90000, 1), (0x1ed2f, 9, 2, 1), (0x1ed38, 1, 400, 1), (0x1ed39, 1, 600, 1),
+    (0x1ed3a, 1, 2000, 1), (0x1ed3b, 1, 10000, 1), (0x1ed3c, 1, 1, 2), (0x1ed3d, 1, 1, 6),
+    (0x1f100, 1, 0, 1), (0x1f101, 10, 0, 1), (0x1f10b, 1, 0, 1), (0x1f10c, 1, 0, 1),
+];
+
+/// The zeros of the non-ASCII decimal digit blocks, each followed by the digits one to nine
+#[rustfmt::skip]
+const DIGIT_ZEROS: [u32; 65] = [
+    0x0660, 0x06f0, 0x07c0, 0x0966, 0x09e6, 0x0a66, 0x0ae6, 0x0b66, 0x0be6, 0x0c66, 0x0ce6, 0x0d66,
+    0x0de6, 0x0e50, 0x0ed0, 0x0f20, 0x1040, 0x1090, 0x17e0, 0x1810, 0x1946, 0x19d0, 0x1a80, 0x1a90,
+    0x1b50, 0x1bb0, 0x1c40, 0x1c50, 0xa620, 0xa8d0, 0xa900, 0xa9d0, 0xa9f0, 0xaa50, 0xabf0, 0xff10,
+    0x104a0, 0x10d30, 0x11066, 0x110f0, 0x11136, 0x111d0, 0x112f0, 0x11450, 0x114d0, 0x11650,
+    0x116c0, 0x11730, 0x118e0, 0x11950, 0x11c50, 0x11d50, 0x11da0, 0x16a60, 0x16ac0, 0x16b50,
+    0x1d7ce, 0x1d7d8, 0x1d7e2, 0x1d7ec, 0x1d7f6, 0x1e140, 0x1e2f0, 0x1e950, 0x1fbf0,
+];
+
+/// Returns whether `c` is a non-ASCII decimal digit, like `'٣'`
+pub(crate) fn is_decimal(c: char) -> bool {
+    !c.is_ascii() && decimal_value(c).is_some()
+}
+
+/// Returns the value of a decimal digit of any script, like `3` for `'٣'`
+pub(crate) fn decimal_value(c: char) -> Option<u8> {
+    if c.is_ascii_digit() {
+        return Some(c as u8 - b'0');
+    }
+
+    let c = u32::from(c);
+    match DIGIT_ZEROS.binary_search(&c) {
+        Ok(_) => Some(0),
+        Err(0) => None,
+        Err(index) => Some(c - DIGIT_ZEROS[index - 1])
+            .filter(|value| *value < 10)
+            .map(|value| value as u8),
+    }
+}
+
+/// Returns the zero of the script `c` is a decimal digit of, like `'٠'` for `'٣'`
+pub(crate) fn zero_of(c: char) -> Option<char> {
+    let value = decimal_value(c)?;
+    char::from_u32(u32::from(c) - u32::from(value))
+}
+
+/// Drops the leading zeros of a run of digits, in whatever script they are
+pub(crate) fn trim_zeros(num: &str) -> &str {
+    num.trim_start_matches(|c| decimal_value(c) == Some(0))
+}
+
+/// Returns the values of the digits of a run of digits
+pub(crate) fn digits(num: &str) -> impl Iterator<Item = u8> + '_ {
+    num.chars().map(|c| decimal_value(c).unwrap_or(0))
+}
+
+/// Writes the digits of a run as ASCII digits, so `"٠٣"` becomes `"03"`
+pub(crate) fn push_ascii_digits(out: &mut String, num: &str) {
+    out.extend(digits(num).map(|digit| char::from(b'0' + digit)));
+}
+
+/// Returns the value of a numeric character that isn't a decimal digit, as a fraction
+pub(crate) fn value(c: char) -> Option<(i64, u16)> {
+    let c = u32::from(c);
+    let index = VALUES
+        .binary_search_by(
+            |(first, len, _, _)| match (*first > c, first + u32::from(*len) <= c) {
+                (true, _) => Ordering::Greater,
+                (_, true) => Ordering::Less,
+                _ => Ordering::Equal,
+            },
+        )
+        .ok()?;
+    let (first, _, numerator, denominator) = VALUES[index];
+    Some((numerator + i64::from(c - first), denominator))
+}
+
+#[test]
+fn test_value() {
+    assert_eq!(value('½'), Some((1, 2)));
+    assert_eq!(value('²'), Some((2, 1)));
+    assert_eq!(value('③'), Some((3, 1)));
+    assert_eq!(value('⑳'), Some((20, 1)));
+    assert_eq!(value('Ⅻ'), Some((12, 1)));
+    assert_eq!(value('\u{f33}'), Some((-1, 2)));
+    assert_eq!(value('7'), None);
+    assert_eq!(value('٣'), None);
+    assert_eq!(value('a'), None);
+
+    assert!(is_decimal('٣'));
+    assert!(is_decimal('\u{1d7ff}'));
+    assert!(!is_decimal('7'));
+    assert!(!is_decimal('½'));
+    assert!(!is_decimal('\u{1d7cd}'));
+
+    assert_eq!(decimal_value('7'), Some(7));
+    assert_eq!(decimal_value('٣'), Some(3));
+    assert_eq!(decimal_value('\u{1d7ff}'), Some(9));
+    assert_eq!(decimal_value('½'), None);
+    assert_eq!(decimal_value('a'), None);
+    assert_eq!(trim_zeros("٠0٠٣0"), "٣0");
+    let mut ascii = String::new();
+    push_ascii_digits(&mut ascii, "٠١٢9");
+    assert_eq!(ascii, "0129");
+}
+
+#[test]
+fn test_tables_agree_with_std() {
+    for c in (0x80..=0x10ffff).filter_map(char::from_u32) {
+        if is_decimal(c) || value(c).is_some() {
+            assert!(c.is_numeric(), "{:?}", c);
+        }
+        assert!(!(is_decimal(c) && value(c).is_some()), "{:?}", c);
+    }
+
+    // Nag Mundari digit zero, added in Unicode 15, stays text until the tables are regenerated
+    assert!('\u{1e4f0}'.is_numeric());
+    assert!(!crate::scan::is_digit('\u{1e4f0}'));
+    assert_eq!(
+        crate::natural_cmp("a\u{1e4f0}", "a\u{1e4f1}"),
+        "a\u{1e4f0}".cmp("a\u{1e4f1}")
+    );
+}
@@ -0,0 +1,1151 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+#[cfg(feature = "unicode")]
+use unicode_normalization::char::is_combining_mark;
+#[cfg(feature = "unicode")]
+use unicode_normalization::UnicodeNormalization;
+
+use crate::alphanumeric::alphanumeric;
+use crate::compare::{compare_runs, Rules};
+use crate::natord::natord;
+use crate::scan;
+use crate::strverscmp::strverscmp;
+use crate::NaturalSorter;
+
+/// How strictly the text between numbers is compared, similar to ICU collation strengths
+///
+/// Each strength compares at all the levels below it as well, so a difference
+/// in case only matters when two strings are otherwise equal. Numbers are
+/// compared the same way at every strength.
+///
+/// Requires the `unicode` feature, which is on by default.
+#[cfg(feature = "unicode")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Strength {
+    /// Ignores both case and accents, `"Ä1" == "a1"`
+    Primary,
+    /// Respects accents but ignores case, `"A1" == "a1"` but `"ä1" != "a1"`
+    Secondary,
+    /// Respects accents and case
+    Tertiary,
+}
+
+/// How a specific character is treated before comparing
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CharClass {
+    /// The character is dropped, `"tex[007]"` reads as `"tex007"` if `[` and `]` are ignored
+    Ignore,
+    /// The character separates segments and compares equal to every other separator
+    Separator,
+    /// The character is read as the given decimal digit, see [`CharClass::digit`]
+    Digit(DecimalDigit),
+}
+
+impl CharClass {
+    /// Reads a character as the decimal digit `value`
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The digit, from 0 to 9
+    ///
+    /// # Examples
+    /// ```
+    /// use natural_sort::CharClass;
+    /// assert!(CharClass::digit(9).is_some());
+    /// assert_eq!(CharClass::digit(10), None);
+    /// ```
+    pub fn digit(value: u8) -> Option<CharClass> {
+        DecimalDigit::new(value).map(CharClass::Digit)
+    }
+}
+
+/// A decimal digit from 0 to 9
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DecimalDigit(u8);
+
+impl DecimalDigit {
+    /// Returns the digit `value`, or `None` if it is greater than 9
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The digit
+    pub fn new(value: u8) -> Option<DecimalDigit> {
+        (value < 10).then_some(DecimalDigit(value))
+    }
+
+    /// Returns the value of the digit
+    pub fn value(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the ASCII character of the digit
+    fn to_char(self) -> char {
+        char::from(b'0' + self.0)
+    }
+}
+
+/// Which algorithm compares the normalized strings
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// Numbers compare by value and ties in value are equal, like [`natural_cmp`](crate::natural_cmp)
+    #[default]
+    Natural,
+    /// Reproduces glibc's `strverscmp`, as used by `ls -v` and `sort -V`
+    ///
+    /// Leading zeros start a fractional part, so `"1.01" < "1.2"`, and more
+    /// leading zeros sort first: `"000" < "00" < "01" < "010" < "09" < "0" < "1"`.
+    /// Like the C function it works on bytes and stops at the first NUL.
+    Strverscmp,
+    /// Orders exactly like `natord::compare` from the `natord` crate
+    ///
+    /// Whitespace is skipped entirely, so `"pic 4"` and `"pic4"` are equal.
+    /// A number starting with `0` on either side is compared digit by digit
+    /// like a fraction: `"1.010" < "1.02" < "1.1"`.
+    Natord,
+    /// Orders exactly like `compare_str` from the `alphanumeric-sort` crate
+    ///
+    /// Only ASCII digits form numbers. Leading zeros only break ties, and the
+    /// string with more of them at the first tie sorts last: `"01" > "1"`.
+    Alphanumeric,
+}
+
+/// How digits right after a decimal point are compared, in [`Mode::Natural`]
+///
+/// A fraction is a number that directly follows another number and a single
+/// `.`, like the `4` in `"2.4"`. A fraction never starts another one, so in
+/// `"1.5.3"` only the `5` is a fraction and the `3` is a whole number.
+///
+/// | | `2.4` vs `2.40` | `2.9` vs `2.10` | `2.04` vs `2.4` |
+/// |---|---|---|---|
+/// | `Version` | less | less | equal |
+/// | `Decimal` | equal | greater | less |
+/// | `Filename` | less | greater | less |
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Fractions {
+    /// Fractions are integers like any other number, so `"2.10"` is version ten of `"2"`
+    #[default]
+    Version,
+    /// Fractions are read as decimals, so `"2.4" == "2.40"` and `"2.9" > "2.10"`
+    Decimal,
+    /// Fractions compare digit by digit as text, so `"2.40" > "2.4"` and `"2.04" < "2.4"`
+    Filename,
+}
+
+/// The invisible characters that steer bidirectional text layout
+const BIDI_CONTROLS: [char; 12] = [
+    '\u{61c}', '\u{200e}', '\u{200f}', '\u{202a}', '\u{202b}', '\u{202c}', '\u{202d}', '\u{202e}',
+    '\u{2066}', '\u{2067}', '\u{2068}', '\u{2069}',
+];
+
+type TiebreakFn = dyn Fn(&str, &str) -> Ordering + Send + Sync;
+
+/// A caller supplied comparison for strings that are otherwise equal
+#[derive(Clone)]
+struct Tiebreak(Arc<TiebreakFn>);
+
+impl fmt::Debug for Tiebreak {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Tiebreak(..)")
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Level {
+    #[cfg(feature = "unicode")]
+    Base,
+    #[cfg(feature = "unicode")]
+    Accents,
+    Exact,
+}
+
+/// Configures a natural comparison
+///
+/// The default options compare exactly like [`natural_cmp`](crate::natural_cmp).
+///
+/// # Examples
+/// ```
+/// use std::cmp::Ordering;
+/// use natural_sort::NaturalOptions;
+/// let options = NaturalOptions::new().ignore_chars("[]");
+/// assert_eq!(options.compare("tex[007]", "tex7"), Ordering::Equal);
+/// assert_eq!(options.compare("tex[10]", "tex9"), Ordering::Greater);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct NaturalOptions {
+    #[cfg(feature = "unicode")]
+    strength: Option<Strength>,
+    mode: Mode,
+    rules: Rules,
+    classes: HashMap<char, CharClass>,
+    digit_separators: Vec<char>,
+    budget: Option<usize>,
+    hex_groups: bool,
+    strip_bidi: bool,
+    uuids: bool,
+    tiebreak: Option<Tiebreak>,
+    #[cfg(feature = "ordinals")]
+    number_words: bool,
+}
+
+impl NaturalOptions {
+    /// Creates the default options
+    pub fn new() -> Self {
+        NaturalOptions::default()
+    }
+
+    /// Sets the collation strength for the text between numbers
+    ///
+    /// Without a strength, text is compared code point by code point.
+    /// Requires the `unicode` feature, which is on by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `strength` - The strictest level to compare at
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use natural_sort::{NaturalOptions, Strength};
+    /// let options = NaturalOptions::new().strength(Strength::Primary);
+    /// assert_eq!(options.compare("Äpfel2", "apfel2"), Ordering::Equal);
+    /// assert_eq!(options.compare("apfel10", "Äpfel2"), Ordering::Greater);
+    /// ```
+    #[cfg(feature = "unicode")]
+    pub fn strength(mut self, strength: Strength) -> Self {
+        self.strength = Some(strength);
+        self
+    }
+
+    /// Sets the algorithm used to compare
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The comparison algorithm
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use natural_sort::{Mode, NaturalOptions};
+    /// let options = NaturalOptions::new().mode(Mode::Strverscmp);
+    /// assert_eq!(options.compare("1.01", "1.2"), Ordering::Less);
+    /// assert_eq!(options.compare("01", "1"), Ordering::Less);
+    /// ```
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets how digits after a decimal point are compared
+    ///
+    /// Only applies in [`Mode::Natural`].
+    ///
+    /// # Arguments
+    ///
+    /// * `fractions` - The policy for fractional digits
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use natural_sort::{Fractions, NaturalOptions};
+    /// let options = NaturalOptions::new().fractions(Fractions::Decimal);
+    /// assert_eq!(options.compare("pi 3.14", "pi 3.140"), Ordering::Equal);
+    /// assert_eq!(options.compare("pi 3.9", "pi 3.14"), Ordering::Greater);
+    /// ```
+    pub fn fractions(mut self, fractions: Fractions) -> Self {
+        self.rules.fractions = fractions;
+        self
+    }
+
+    /// Sets how a single character is treated
+    ///
+    /// Classification happens before anything else, so ignored characters and
+    /// digits declared here take part in splitting strings into numbers and text.
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - The character to classify
+    /// * `class` - How to treat it
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use natural_sort::{CharClass, NaturalOptions};
+    /// let options = NaturalOptions::new().classify('Ⅹ', CharClass::digit(9).unwrap());
+    /// assert_eq!(options.compare("v9", "vⅩ"), Ordering::Equal);
+    /// ```
+    pub fn classify(mut self, c: char, class: CharClass) -> Self {
+        self.classes.insert(c, class);
+        self
+    }
+
+    /// Ignores every character of `chars`
+    ///
+    /// # Arguments
+    ///
+    /// * `chars` - The characters to drop before comparing
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use natural_sort::NaturalOptions;
+    /// let options = NaturalOptions::new().ignore_chars("[]");
+    /// assert_eq!(options.compare("tex[007]_v2", "tex8_v2"), Ordering::Less);
+    /// ```
+    pub fn ignore_chars(self, chars: &str) -> Self {
+        chars
+            .chars()
+            .fold(self, |options, c| options.classify(c, CharClass::Ignore))
+    }
+
+    /// Treats every character of `chars` as a separator
+    ///
+    /// All separators compare as a single space, so `"a_1"`, `"a-1"` and `"a 1"` are equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `chars` - The characters to treat as separators
+    pub fn separator_chars(self, chars: &str) -> Self {
+        chars
+            .chars()
+            .fold(self, |options, c| options.classify(c, CharClass::Separator))
+    }
+
+    /// Reads numbers across the characters of `chars`, like `_` in `1_000`
+    ///
+    /// A digit separator is dropped when it sits between two numeric
+    /// characters, so `"file_1_000"` reads as `"file_1000"`. Anywhere else it
+    /// is kept as text. Separators are applied after [`classify`](Self::classify).
+    ///
+    /// # Arguments
+    ///
+    /// * `chars` - The characters that may continue a number
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use natural_sort::NaturalOptions;
+    /// let options = NaturalOptions::new().digit_separator_chars("_\u{2009}");
+    /// assert_eq!(options.compare("file_1_000", "file_999"), Ordering::Greater);
+    /// assert_eq!(options.compare("1\u{2009}234 km", "1234 km"), Ordering::Equal);
+    /// ```
+    pub fn digit_separator_chars(mut self, chars: &str) -> Self {
+        self.digit_separators.extend(chars.chars());
+        self
+    }
+
+    /// Compares characters like `'½'`, `'²'`, `'③'` or `'Ⅻ'` by their numeric value
+    ///
+    /// By default only decimal digits form numbers and such characters are
+    /// text. With this option each of them is a number of its own, equal to
+    /// any other number of the same value. Only applies in [`Mode::Natural`].
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to read Unicode numeric values
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use natural_sort::NaturalOptions;
+    /// let options = NaturalOptions::new().numeric_values(true);
+    /// assert_eq!(options.compare("step ③", "step 10"), Ordering::Less);
+    /// assert_eq!(options.compare("chapter Ⅻ", "chapter 12"), Ordering::Equal);
+    /// assert_eq!(options.compare("cup ½", "cup ¾"), Ordering::Less);
+    /// ```
+    pub fn numeric_values(mut self, enabled: bool) -> Self {
+        self.rules.numeric_values = enabled;
+        self
+    }
+
+    /// Stops reading numbers after the first `n` of them
+    ///
+    /// The first `n` text runs and numbers are compared naturally, whatever
+    /// follows them compares as a plain string. Only applies in [`Mode::Natural`].
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - How many numbers to compare by value
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use natural_sort::NaturalOptions;
+    /// let options = NaturalOptions::new().max_segments(1);
+    /// assert_eq!(options.compare("9_b10", "10_a2"), Ordering::Less);
+    /// assert_eq!(options.compare("0001_v10", "1_v9"), Ordering::Less);
+    /// ```
+    pub fn max_segments(mut self, n: usize) -> Self {
+        self.rules.max_segments = Some(n);
+        self
+    }
+
+    /// Compares colon or dash separated hex groups, like MAC addresses, by value
+    ///
+    /// A run of at least two groups of one or two hex digits, joined by the
+    /// same `:` or `-` and not touching other letters or digits, is read as
+    /// numbers if its groups all have two digits or one of two digits has a
+    /// letter. Anything else, like `"a-99-1"`, compares as usual.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to recognize hex groups
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use natural_sort::NaturalOptions;
+    /// let options = NaturalOptions::new().hex_groups(true);
+    /// assert_eq!(options.compare("sw-aa:0b:10", "sw-aa:0b:2"), Ordering::Greater);
+    /// assert_eq!(options.compare("sw-aa:0b:10", "sw-aa:0c:00"), Ordering::Less);
+    /// ```
+    pub fn hex_groups(mut self, enabled: bool) -> Self {
+        self.hex_groups = enabled;
+        self
+    }
+
+    /// Drops bidirectional control characters before comparing
+    ///
+    /// Strings are always compared in logical order, the order they are
+    /// stored in, whatever their direction on screen. Marks like U+200F
+    /// RIGHT-TO-LEFT MARK or the isolates U+2066 to U+2069 are text by
+    /// default, so a mark between a word and a number changes the order.
+    /// With this option they are ignored, and never split or join numbers.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to drop bidi controls
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use natural_sort::NaturalOptions;
+    /// let options = NaturalOptions::new().strip_bidi(true);
+    /// assert_eq!(options.compare("קובץ \u{2066}10\u{2069}", "קובץ 10"), Ordering::Equal);
+    /// assert_eq!(options.compare("קובץ 1\u{200e}0", "קובץ 9"), Ordering::Greater);
+    /// ```
+    pub fn strip_bidi(mut self, enabled: bool) -> Self {
+        self.strip_bidi = enabled;
+        self
+    }
+
+    /// Compares embedded UUIDs as opaque tokens
+    ///
+    /// A UUID in the usual `8-4-4-4-12` hex form, not touching other letters or
+    /// digits, compares equal to every other UUID, so the text and numbers
+    /// around it decide the order. Its digits are never read as numbers. UUIDs
+    /// are recognized before characters are classified.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to recognize UUIDs
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use natural_sort::NaturalOptions;
+    /// let options = NaturalOptions::new().uuids(true);
+    /// assert_eq!(
+    ///     options.compare(
+    ///         "job-ffffffff-1b4d-4e8f-9a0b-3c5d7e9f1a2b-9",
+    ///         "job-00000000-1b4d-4e8f-9a0b-3c5d7e9f1a2b-12"
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// ```
+    pub fn uuids(mut self, enabled: bool) -> Self {
+        self.uuids = enabled;
+        self
+    }
+
+    /// Reads English number words like `"third"` or `"twenty-one"` as numbers
+    ///
+    /// Ordinal suffixes after digits (`"2nd"`) are dropped as well, so
+    /// `"First draft"`, `"2nd draft"` and `"Third draft"` sort in that order.
+    /// Requires the `ordinals` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to recognize number words
+    ///
+    /// # Examples
+    /// ```
+    /// use natural_sort::NaturalOptions;
+    /// let mut list = vec!["Third draft", "First draft", "2nd draft", "10th draft"];
+    /// NaturalOptions::new().number_words(true).sort(&mut list);
+    /// assert_eq!(list, vec!["First draft", "2nd draft", "Third draft", "10th draft"]);
+    /// ```
+    #[cfg(feature = "ordinals")]
+    pub fn number_words(mut self, enabled: bool) -> Self {
+        self.number_words = enabled;
+        self
+    }
+
+    /// Limits how much of each string is compared naturally
+    ///
+    /// Only the first `bytes` bytes of each string are normalized and split
+    /// into text and numbers, anything after that is compared as a plain
+    /// string if the beginnings are equal. The limit applies to the input
+    /// before any other option rewrites it, so it bounds all the work spent
+    /// per comparison on untrusted input. A number crossing the limit is cut in two.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - How many bytes of each string to compare naturally
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use natural_sort::NaturalOptions;
+    /// let options = NaturalOptions::new().budget(4);
+    /// assert_eq!(options.compare("v9-a10", "v10-a9"), Ordering::Less);
+    /// assert_eq!(options.compare("v10-a10", "v10-a9"), Ordering::Less);
+    /// ```
+    pub fn budget(mut self, bytes: usize) -> Self {
+        self.budget = Some(bytes);
+        self
+    }
+
+    /// Orders strings that compare equal with `tiebreak`, instead of leaving them equal
+    ///
+    /// `tiebreak` sees the original strings, before any normalization, and
+    /// only runs when every other rule found them equal, like `"a01"` and
+    /// `"a1"`. It must be a total order itself for sorting to be consistent.
+    ///
+    /// # Arguments
+    ///
+    /// * `tiebreak` - Compares two naturally equal strings
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use natural_sort::NaturalOptions;
+    /// let options = NaturalOptions::new().with_tiebreak(|a, b| a.len().cmp(&b.len()));
+    /// assert_eq!(options.compare("a01", "a1"), Ordering::Greater);
+    /// assert_eq!(options.compare("a02", "a1"), Ordering::Greater);
+    /// assert_eq!(options.compare("a1", "a10"), Ordering::Less);
+    /// ```
+    pub fn with_tiebreak<F>(mut self, tiebreak: F) -> Self
+    where
+        F: Fn(&str, &str) -> Ordering + Send + Sync + 'static,
+    {
+        self.tiebreak = Some(Tiebreak(Arc::new(tiebreak)));
+        self
+    }
+
+    /// Compares two string slices using these options
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The left hand side
+    /// * `b` - The right hand side
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        self.compare_with_buffers(a, b, &mut String::new(), &mut String::new())
+    }
+
+    /// Compares like `compare`, normalizing into caller provided buffers
+    pub(crate) fn compare_with_buffers(
+        &self,
+        a: &str,
+        b: &str,
+        left: &mut String,
+        right: &mut String,
+    ) -> Ordering {
+        for level in self.levels() {
+            self.normalize(a, *level, left);
+            self.normalize(b, *level, right);
+
+            match self.compare_normalized(left, right) {
+                Ordering::Equal => {}
+                ord => return ord,
+            }
+        }
+
+        self.compare_tails(a, b).then_with(|| self.tiebreak(a, b))
+    }
+
+    /// Compares what is left of two strings after the budget, as plain strings
+    pub(crate) fn compare_tails(&self, a: &str, b: &str) -> Ordering {
+        self.split_budget(a).1.cmp(self.split_budget(b).1)
+    }
+
+    /// Splits a string into the part within the budget and the rest
+    fn split_budget<'a>(&self, s: &'a str) -> (&'a str, &'a str) {
+        match self.budget {
+            None => (s, ""),
+            Some(bytes) => s.split_at(char_boundary(s, bytes)),
+        }
+    }
+
+    /// Breaks a tie between two equal strings, if a tiebreak is set
+    pub(crate) fn tiebreak(&self, a: &str, b: &str) -> Ordering {
+        match &self.tiebreak {
+            Some(Tiebreak(tiebreak)) => tiebreak(a, b),
+            None => Ordering::Equal,
+        }
+    }
+
+    /// Compares two strings that went through `normalize` already
+    pub(crate) fn compare_normalized(&self, a: &str, b: &str) -> Ordering {
+        match self.mode {
+            Mode::Natural => compare_runs(a, b, &self.rules, str::cmp),
+            Mode::Strverscmp => strverscmp(a, b),
+            Mode::Natord => natord(a, b),
+            Mode::Alphanumeric => alphanumeric(a, b),
+        }
+    }
+
+    /// Sorts a slice using these options
+    ///
+    /// Every value is normalized once up front rather than on every comparison.
+    /// The sort is stable.
+    ///
+    /// # Arguments
+    ///
+    /// * `vals` - A slice of string-like values
+    pub fn sort<T: AsRef<str>>(&self, vals: &mut [T]) {
+        NaturalSorter::with_options(self.clone()).sort(vals);
+    }
+
+    pub(crate) fn levels(&self) -> &'static [Level] {
+        #[cfg(feature = "unicode")]
+        match self.strength {
+            None => &[Level::Exact],
+            Some(Strength::Primary) => &[Level::Base],
+            Some(Strength::Secondary) => &[Level::Base, Level::Accents],
+            Some(Strength::Tertiary) => &[Level::Base, Level::Accents, Level::Exact],
+        }
+
+        #[cfg(not(feature = "unicode"))]
+        &[Level::Exact]
+    }
+
+    pub(crate) fn normalize(&self, s: &str, level: Level, out: &mut String) {
+        out.clear();
+
+        let s = self.preprocess(self.split_budget(s).0);
+        match level {
+            #[cfg(feature = "unicode")]
+            Level::Base => out.extend(
+                s.nfd()
+                    .filter(|c| !is_combining_mark(*c))
+                    .flat_map(char::to_lowercase),
+            ),
+            #[cfg(feature = "unicode")]
+            Level::Accents => out.extend(s.nfd().flat_map(char::to_lowercase)),
+            Level::Exact => out.push_str(&s),
+        }
+    }
+
+    fn preprocess<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        let s = match self.strip_bidi {
+            true => Cow::Owned(s.chars().filter(|c| !BIDI_CONTROLS.contains(c)).collect()),
+            false => Cow::Borrowed(s),
+        };
+
+        let s = match self.uuids {
+            true => Cow::Owned(crate::uuid::mask_uuids(&s)),
+            false => s,
+        };
+
+        let s = if self.classes.is_empty() {
+            s
+        } else {
+            Cow::Owned(s.chars().filter_map(|c| self.classify_char(c)).collect())
+        };
+
+        let s = match self.digit_separators.is_empty() {
+            true => s,
+            false => Cow::Owned(self.join_numbers(&s)),
+        };
+
+        let s = match self.hex_groups {
+            true => Cow::Owned(crate::hex::replace_hex_groups(&s)),
+            false => s,
+        };
+
+        #[cfg(feature = "ordinals")]
+        if self.number_words {
+            return Cow::Owned(crate::ordinal::replace_number_words(&s));
+        }
+
+        s
+    }
+
+    /// Drops the digit separators that sit between two numeric characters
+    fn join_numbers(&self, s: &str) -> String {
+        let mut out = String::with_capacity(s.len());
+        let mut chars = s.chars().peekable();
+        let mut after_numeric = false;
+
+        while let Some(c) = chars.next() {
+            let joins = after_numeric
+                && self.digit_separators.contains(&c)
+                && chars.peek().is_some_and(|next| scan::is_digit(*next));
+            if !joins {
+                out.push(c);
+                after_numeric = scan::is_digit(c);
+            }
+        }
+
+        out
+    }
+
+    fn classify_char(&self, c: char) -> Option<char> {
+        match self.classes.get(&c) {
+            None => Some(c),
+            Some(CharClass::Ignore) => None,
+            Some(CharClass::Separator) => Some(' '),
+            Some(CharClass::Digit(digit)) => Some(digit.to_char()),
+        }
+    }
+}
+
+/// Returns the largest char boundary of `s` that is at most `index`
+fn char_boundary(s: &str, index: usize) -> usize {
+    (0..=index.min(s.len()))
+        .rev()
+        .find(|i| s.is_char_boundary(*i))
+        .unwrap_or(0)
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn test_strength() {
+    let primary = NaturalOptions::new().strength(Strength::Primary);
+    assert_eq!(primary.compare("Résumé 2", "resume 2"), Ordering::Equal);
+    assert_eq!(primary.compare("Résumé 10", "resume 2"), Ordering::Greater);
+    assert_eq!(primary.compare("B1", "a2"), Ordering::Greater);
+
+    let secondary = NaturalOptions::new().strength(Strength::Secondary);
+    assert_eq!(secondary.compare("RESUME 2", "resume 2"), Ordering::Equal);
+    assert_eq!(secondary.compare("résumé 2", "resume 2"), Ordering::Greater);
+    assert_eq!(secondary.compare("résumé 2", "resume 10"), Ordering::Less);
+    assert_eq!(
+        secondary.compare("re\u{301}sume 2", "résume 2"),
+        Ordering::Equal
+    );
+
+    let tertiary = NaturalOptions::new().strength(Strength::Tertiary);
+    assert_eq!(tertiary.compare("Resume 2", "resume 2"), Ordering::Less);
+    assert_eq!(tertiary.compare("Resume 2", "resume 1"), Ordering::Greater);
+
+    let exact = NaturalOptions::new();
+    assert_eq!(exact.compare("B1", "a2"), Ordering::Less);
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn test_options_sort() {
+    let mut list = vec!["file10", "File2", "fíle1", "FILE1", "file1"];
+
+    NaturalOptions::new()
+        .strength(Strength::Tertiary)
+        .sort(&mut list);
+    assert_eq!(list, vec!["FILE1", "file1", "fíle1", "File2", "file10"]);
+
+    NaturalOptions::new()
+        .strength(Strength::Primary)
+        .sort(&mut list);
+    assert_eq!(list, vec!["FILE1", "file1", "fíle1", "File2", "file10"]);
+}
+
+#[test]
+fn test_char_classes() {
+    let options = NaturalOptions::new().ignore_chars("[]");
+    assert_eq!(options.compare("tex[007]_v2", "tex8_v2"), Ordering::Less);
+    assert_eq!(options.compare("tex[007]_v2", "tex7_v2"), Ordering::Equal);
+
+    let options = NaturalOptions::new().separator_chars("_-.");
+    assert_eq!(options.compare("a_1", "a-1"), Ordering::Equal);
+    assert_eq!(options.compare("a.10", "a 9"), Ordering::Greater);
+
+    #[cfg(feature = "unicode")]
+    {
+        let options = NaturalOptions::new()
+            .classify('٣', CharClass::digit(3).unwrap())
+            .classify('٤', CharClass::digit(4).unwrap())
+            .strength(Strength::Primary);
+        assert_eq!(options.compare("Page ٣٤", "page 34"), Ordering::Equal);
+        assert_eq!(options.compare("page ٤", "page 34"), Ordering::Less);
+    }
+
+    assert_eq!(CharClass::digit(10), None);
+    assert_eq!(DecimalDigit::new(7).map(DecimalDigit::value), Some(7));
+}
+
+#[test]
+fn test_budget() {
+    let options = NaturalOptions::new().budget(3);
+    assert_eq!(options.compare("ab9", "ab10"), Ordering::Greater);
+    assert_eq!(options.compare("ab10", "ab9"), Ordering::Less);
+    assert_eq!(options.compare("ab10", "ab1"), Ordering::Greater);
+    assert_eq!(options.compare("äö9", "äö10"), Ordering::Greater);
+
+    let mut list = vec!["x2-10", "x2-9", "x1-10"];
+    NaturalOptions::new().budget(2).sort(&mut list);
+    assert_eq!(list, vec!["x1-10", "x2-10", "x2-9"]);
+
+    // the budget counts bytes of the input, before separators or hex groups are rewritten
+    let options = NaturalOptions::new()
+        .budget(3)
+        .digit_separator_chars("_")
+        .hex_groups(true);
+    assert_eq!(options.compare("a1_5", "a15"), Ordering::Less);
+    assert_eq!(options.compare("v1_2", "v1_10"), Ordering::Greater);
+    assert_eq!(options.compare("aa:bb", "aa:0c"), Ordering::Greater);
+
+    let mut sorter = NaturalSorter::with_options(NaturalOptions::new().budget(2));
+    assert_eq!(
+        sorter.rank(&["x2-10", "x2-9", "x1-10", "x2-10"]),
+        vec![2, 4, 1, 2]
+    );
+}
+
+#[test]
+fn test_mode() {
+    let mut list = vec!["10", "9", "1", "0", "09", "010", "01", "00", "000"];
+    NaturalOptions::new().mode(Mode::Strverscmp).sort(&mut list);
+    assert_eq!(
+        list,
+        vec!["000", "00", "01", "010", "09", "0", "1", "9", "10"]
+    );
+
+    #[cfg(feature = "unicode")]
+    {
+        let options = NaturalOptions::new()
+            .mode(Mode::Strverscmp)
+            .strength(Strength::Primary);
+        assert_eq!(options.compare("File-1.01", "file-1.2"), Ordering::Less);
+    }
+    assert_eq!(NaturalOptions::new().compare("1.01", "1.2"), Ordering::Less);
+    assert_eq!(NaturalOptions::new().compare("01", "1"), Ordering::Equal);
+}
+
+#[test]
+fn test_fractions() {
+    let pairs = [("2.4", "2.40"), ("2.9", "2.10"), ("2.04", "2.4")];
+    let expected = [
+        (
+            Fractions::Version,
+            [Ordering::Less, Ordering::Less, Ordering::Equal],
+        ),
+        (
+            Fractions::Decimal,
+            [Ordering::Equal, Ordering::Greater, Ordering::Less],
+        ),
+        (
+            Fractions::Filename,
+            [Ordering::Less, Ordering::Greater, Ordering::Less],
+        ),
+    ];
+
+    for (fractions, orderings) in expected {
+        let options = NaturalOptions::new().fractions(fractions);
+        for ((a, b), ord) in pairs.iter().zip(orderings) {
+            assert_eq!(options.compare(a, b), ord, "{:?} {} {}", fractions, a, b);
+            assert_eq!(options.compare(b, a), ord.reverse());
+        }
+    }
+
+    let decimal = NaturalOptions::new().fractions(Fractions::Decimal);
+    assert_eq!(decimal.compare("v.5", "v.40"), Ordering::Less);
+    assert_eq!(decimal.compare("1.5.3", "1.5.21"), Ordering::Less);
+    assert_eq!(decimal.compare("1.50.3", "1.5.21"), Ordering::Less);
+    assert_eq!(decimal.compare("1.9.3", "1.10.3"), Ordering::Greater);
+    assert_eq!(decimal.compare("a1-5", "a1-40"), Ordering::Less);
+}
+
+#[test]
+fn test_hex_groups() {
+    let mut list = vec![
+        "sw-aa:0b:2",
+        "sw-aa:0b:10",
+        "sw-AA:0B:3",
+        "sw-a:0b:1",
+        "sw-b",
+    ];
+    NaturalOptions::new().hex_groups(true).sort(&mut list);
+    assert_eq!(
+        list,
+        vec![
+            "sw-a:0b:1",
+            "sw-aa:0b:2",
+            "sw-AA:0B:3",
+            "sw-aa:0b:10",
+            "sw-b"
+        ]
+    );
+
+    let options = NaturalOptions::new().hex_groups(true);
+    assert_eq!(options.compare("00-1a-ff", "00-1A-FF"), Ordering::Equal);
+    assert_eq!(options.compare("db-10", "db-9"), Ordering::Greater);
+    assert_eq!(options.compare("a-99-1", "a-100-1"), Ordering::Less);
+    assert_eq!(options.compare("00:09:ff", "00:10:00"), Ordering::Less);
+}
+
+#[test]
+fn test_max_segments() {
+    let plain = NaturalOptions::new().max_segments(0);
+    assert_eq!(plain.compare("a10", "a9"), Ordering::Less);
+
+    let options = NaturalOptions::new().max_segments(2);
+    assert_eq!(options.compare("r1 s10 t10", "r1 s9 t9"), Ordering::Greater);
+    assert_eq!(options.compare("r1 s10 t10", "r1 s10 t9"), Ordering::Less);
+    assert_eq!(options.compare("r1 s10", "r1 s10"), Ordering::Equal);
+    assert_eq!(options.compare("r1", "r1 s1"), Ordering::Less);
+
+    let mut list = vec!["0010_b2", "0002_c10", "0002_c9", "0001_a"];
+    NaturalOptions::new().max_segments(1).sort(&mut list);
+    assert_eq!(list, vec!["0001_a", "0002_c10", "0002_c9", "0010_b2"]);
+}
+
+#[test]
+fn test_digit_separators() {
+    let options = NaturalOptions::new().digit_separator_chars("_ ");
+    assert_eq!(options.compare("file_1_000", "file_999"), Ordering::Greater);
+    assert_eq!(options.compare("file_1_000", "file_1000"), Ordering::Equal);
+    assert_eq!(options.compare("1 234", "1234"), Ordering::Equal);
+    assert_eq!(options.compare("x_1__0", "x_10"), Ordering::Less);
+    assert_eq!(options.compare("v_1_", "v_1"), Ordering::Greater);
+    assert_eq!(options.compare("a_b", "a b"), Ordering::Greater);
+
+    let plain = NaturalOptions::new();
+    assert_eq!(plain.compare("file_1_000", "file_999"), Ordering::Less);
+
+    let classified = NaturalOptions::new()
+        .classify('٣', CharClass::digit(3).unwrap())
+        .digit_separator_chars("_");
+    assert_eq!(classified.compare("٣_000", "3000"), Ordering::Equal);
+}
+
+#[test]
+fn test_compat_modes() {
+    // Expected orders were produced by sorting `corpus` with natord 1.0.9 and
+    // alphanumeric-sort 1.5.8 using the stable `sort_by`
+    let corpus = [
+        "shot-2",
+        "shot-10",
+        "shot-01",
+        "shot-1",
+        "shot-001",
+        "shot 3",
+        "shot3a",
+        "Shot-4",
+        "1點",
+        "1-1點",
+        "01",
+        "1a",
+        "001",
+        "0000",
+        "000",
+        "1.010",
+        "1.02",
+        "1.1",
+        "img12.png",
+        "img10.png",
+        "img2.png",
+        "IMG1.png",
+        "x9y",
+        "x09y1",
+        "ab",
+        "a b",
+    ];
+
+    let mut list = corpus.to_vec();
+    NaturalOptions::new().mode(Mode::Natord).sort(&mut list);
+    assert_eq!(
+        list,
+        vec![
+            "000",
+            "0000",
+            "001",
+            "01",
+            "1-1點",
+            "1.010",
+            "1.02",
+            "1.1",
+            "1a",
+            "1點",
+            "IMG1.png",
+            "Shot-4",
+            "ab",
+            "a b",
+            "img2.png",
+            "img10.png",
+            "img12.png",
+            "shot-001",
+            "shot-01",
+            "shot-1",
+            "shot-2",
+            "shot-10",
+            "shot 3",
+            "shot3a",
+            "x09y1",
+            "x9y",
+        ]
+    );
+
+    let mut list = corpus.to_vec();
+    NaturalOptions::new()
+        .mode(Mode::Alphanumeric)
+        .sort(&mut list);
+    assert_eq!(
+        list,
+        vec![
+            "000",
+            "0000",
+            "01",
+            "001",
+            "1點",
+            "1-1點",
+            "1.1",
+            "1.02",
+            "1.010",
+            "1a",
+            "IMG1.png",
+            "Shot-4",
+            "a b",
+            "ab",
+            "img2.png",
+            "img10.png",
+            "img12.png",
+            "shot 3",
+            "shot-1",
+            "shot-01",
+            "shot-001",
+            "shot-2",
+            "shot-10",
+            "shot3a",
+            "x9y",
+            "x09y1",
+        ]
+    );
+}
+
+#[test]
+fn test_numeric_values() {
+    let options = NaturalOptions::new().numeric_values(true);
+    assert_eq!(options.compare("x½", "x1"), Ordering::Less);
+    assert_eq!(options.compare("x½", "x0"), Ordering::Greater);
+    assert_eq!(options.compare("x²", "x10"), Ordering::Less);
+    assert_eq!(options.compare("1½", "1¾"), Ordering::Less);
+    assert_eq!(options.compare("1½", "2"), Ordering::Less);
+    assert_eq!(options.compare("③a", "3b"), Ordering::Less);
+    assert_eq!(options.compare("x③", "x③"), Ordering::Equal);
+    assert_eq!(options.compare("x3", "x03"), Ordering::Equal);
+    assert_eq!(options.compare("x٣", "x⑤"), Ordering::Less);
+    assert_eq!(options.compare("x٤", "x③"), Ordering::Greater);
+    assert_eq!(
+        options.compare("v100000000000000000000", "v⅓"),
+        Ordering::Greater
+    );
+
+    let mut list = vec!["Track ⑫", "Track 2", "Track ①", "Track ⅒", "Track 10"];
+    options.sort(&mut list);
+    assert_eq!(
+        list,
+        vec!["Track ⅒", "Track ①", "Track 2", "Track 10", "Track ⑫"]
+    );
+
+    let plain = NaturalOptions::new();
+    assert_eq!(plain.compare("x²", "x10"), Ordering::Greater);
+    assert_eq!(plain.compare("x③", "x④"), Ordering::Less);
+}
+
+#[test]
+fn test_strip_bidi() {
+    let plain = NaturalOptions::new();
+    assert_eq!(plain.compare("קובץ 2", "קובץ 10"), Ordering::Less);
+    assert_eq!(plain.compare("ملف 10", "ملف 9"), Ordering::Greater);
+    assert_eq!(
+        plain.compare("קובץ \u{200f}10", "קובץ 10"),
+        Ordering::Greater
+    );
+
+    let options = NaturalOptions::new().strip_bidi(true);
+    for control in BIDI_CONTROLS {
+        let marked = format!("קובץ {}1{}0", control, control);
+        assert_eq!(options.compare(&marked, "קובץ 10"), Ordering::Equal);
+        assert_eq!(options.compare(&marked, "קובץ 9"), Ordering::Greater);
+    }
+
+    let mut list = vec![
+        "\u{202b}קובץ 10\u{202c}",
+        "קובץ 9",
+        "\u{2067}קובץ 1\u{2069}",
+    ];
+    options.sort(&mut list);
+    assert_eq!(
+        list,
+        vec![
+            "\u{2067}קובץ 1\u{2069}",
+            "קובץ 9",
+            "\u{202b}קובץ 10\u{202c}"
+        ]
+    );
+}
+
+#[test]
+fn test_uuids() {
+    let mut list = vec![
+        "job-7f3a9c2e-1b4d-4e8f-9a0b-3c5d7e9f1a2b-12",
+        "job-0a1b2c3d-0000-4000-8000-000000000000-2",
+        "job-FFFFFFFF-FFFF-FFFF-FFFF-FFFFFFFFFFFF-10",
+        "job-1",
+    ];
+    NaturalOptions::new().uuids(true).sort(&mut list);
+    assert_eq!(
+        list,
+        vec![
+            "job-1",
+            "job-0a1b2c3d-0000-4000-8000-000000000000-2",
+            "job-FFFFFFFF-FFFF-FFFF-FFFF-FFFFFFFFFFFF-10",
+            "job-7f3a9c2e-1b4d-4e8f-9a0b-3c5d7e9f1a2b-12",
+        ]
+    );
+
+    let options = NaturalOptions::new().uuids(true);
+    assert_eq!(
+        options.compare(
+            "a-7f3a9c2e-1b4d-4e8f-9a0b-3c5d7e9f1a2b",
+            "a-00000000-0000-0000-0000-000000000000"
+        ),
+        Ordering::Equal
+    );
+    assert_eq!(
+        options.compare("a-7f3a9c2e-1b4d-4e8f-9a0b-3c5d7e9f1a2b", "a-9"),
+        Ordering::Greater
+    );
+    assert_eq!(
+        NaturalOptions::new().compare(
+            "a-7f3a9c2e-1b4d-4e8f-9a0b-3c5d7e9f1a2b",
+            "a-00000000-0000-0000-0000-000000000000"
+        ),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn test_tiebreak() {
+    let ids: HashMap<&str, u32> = [("a01", 3), ("a1", 1), ("a001", 2)].into_iter().collect();
+    let options = NaturalOptions::new().with_tiebreak(move |a, b| ids[a].cmp(&ids[b]));
+
+    let mut list = vec!["a001", "a2", "a01", "a1", "a0"];
+    options.sort(&mut list);
+    assert_eq!(list, vec!["a0", "a1", "a001", "a01", "a2"]);
+    assert_eq!(options.compare("a01", "a001"), Ordering::Greater);
+
+    #[cfg(feature = "unicode")]
+    {
+        let options = NaturalOptions::new()
+            .strength(Strength::Primary)
+            .with_tiebreak(|a, b| b.cmp(a));
+        assert_eq!(options.compare("File", "file"), Ordering::Greater);
+        assert_eq!(options.compare("file2", "File10"), Ordering::Less);
+    }
+    assert!(format!("{:?}", options).contains("Tiebreak(..)"));
+}
@@ -0,0 +1,140 @@
+//! Recognizes English number words, enabled with the `ordinals` feature
+
+const UNITS: [(&str, &str); 20] = [
+    ("zero", "zeroth"),
+    ("one", "first"),
+    ("two", "second"),
+    ("three", "third"),
+    ("four", "fourth"),
+    ("five", "fifth"),
+    ("six", "sixth"),
+    ("seven", "seventh"),
+    ("eight", "eighth"),
+    ("nine", "ninth"),
+    ("ten", "tenth"),
+    ("eleven", "eleventh"),
+    ("twelve", "twelfth"),
+    ("thirteen", "thirteenth"),
+    ("fourteen", "fourteenth"),
+    ("fifteen", "fifteenth"),
+    ("sixteen", "sixteenth"),
+    ("seventeen", "seventeenth"),
+    ("eighteen", "eighteenth"),
+    ("nineteen", "nineteenth"),
+];
+
+const TENS: [(&str, &str); 8] = [
+    ("twenty", "twentieth"),
+    ("thirty", "thirtieth"),
+    ("forty", "fortieth"),
+    ("fifty", "fiftieth"),
+    ("sixty", "sixtieth"),
+    ("seventy", "seventieth"),
+    ("eighty", "eightieth"),
+    ("ninety", "ninetieth"),
+];
+
+const SUFFIXES: [&str; 4] = ["st", "nd", "rd", "th"];
+
+fn unit(word: &str) -> Option<u32> {
+    UNITS
+        .iter()
+        .position(|(cardinal, ordinal)| word == *cardinal || word == *ordinal)
+        .map(|n| n as u32)
+}
+
+fn tens(word: &str) -> Option<u32> {
+    TENS.iter()
+        .position(|(cardinal, ordinal)| word == *cardinal || word == *ordinal)
+        .map(|n| n as u32 * 10 + 20)
+}
+
+fn word_value(word: &str) -> Option<u32> {
+    unit(word).or_else(|| tens(word))
+}
+
+fn word_end(s: &str) -> usize {
+    s.find(|c: char| !c.is_alphabetic()).unwrap_or(s.len())
+}
+
+fn at_boundary(s: &str) -> bool {
+    !s.starts_with(char::is_alphanumeric)
+}
+
+/// Replaces number words with digits and drops ordinal suffixes
+///
+/// Words from "zero" to "ninety-nine", cardinal or ordinal and in any case,
+/// are recognized when they stand on their own: `"Third draft"` becomes
+/// `"3 draft"` and `"2nd draft"` becomes `"2 draft"`. A word touching a
+/// letter or digit on either side, as in `"file2one"`, is left alone.
+pub(crate) fn replace_number_words(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    let mut prev = None;
+
+    while let Some(c) = rest.chars().next() {
+        if !c.is_alphabetic() {
+            out.push(c);
+            prev = Some(c);
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+
+        let end = word_end(rest);
+        let (word, tail) = rest.split_at(end);
+        let lower = word.to_lowercase();
+        rest = tail;
+        let before = prev.take();
+
+        if !at_boundary(tail) {
+            out.push_str(word);
+            continue;
+        }
+        if before.is_some_and(|p| p.is_ascii_digit()) && SUFFIXES.contains(&lower.as_str()) {
+            continue;
+        }
+        if before.is_some_and(char::is_alphanumeric) {
+            out.push_str(word);
+            continue;
+        }
+
+        let value = match (tens(&lower), tail.strip_prefix('-')) {
+            (Some(t), Some(next)) => {
+                let next_end = word_end(next);
+                match unit(&next[..next_end].to_lowercase()) {
+                    Some(u) if (1..10).contains(&u) && at_boundary(&next[next_end..]) => {
+                        rest = &next[next_end..];
+                        Some(t + u)
+                    }
+                    _ => Some(t),
+                }
+            }
+            _ => word_value(&lower),
+        };
+
+        match value {
+            Some(n) => out.push_str(&n.to_string()),
+            None => out.push_str(word),
+        }
+    }
+
+    out
+}
+
+#[test]
+fn test_replace_number_words() {
+    assert_eq!(replace_number_words("First draft"), "1 draft");
+    assert_eq!(replace_number_words("2nd draft"), "2 draft");
+    assert_eq!(replace_number_words("Third draft"), "3 draft");
+    assert_eq!(replace_number_words("chapter twenty-one"), "chapter 21");
+    assert_eq!(replace_number_words("Twenty-Third"), "23");
+    assert_eq!(replace_number_words("forty-winks"), "40-winks");
+    assert_eq!(replace_number_words("someone 21st"), "someone 21");
+    assert_eq!(replace_number_words("the nth time"), "the nth time");
+    assert_eq!(replace_number_words("file2one"), "file2one");
+    assert_eq!(replace_number_words("2ndone"), "2ndone");
+    assert_eq!(replace_number_words("one2"), "one2");
+    assert_eq!(replace_number_words("2nd3"), "2nd3");
+    assert_eq!(replace_number_words("twenty-one2"), "20-one2");
+    assert_eq!(replace_number_words("(one)"), "(1)");
+}
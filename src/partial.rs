@@ -0,0 +1,104 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::Natural;
+
+/// Lazily yields values in natural order
+///
+/// Building the sorter is O(n) and every call to `next` is O(log n), so
+/// taking the first few values of a big list is much cheaper than sorting
+/// all of it. Values that compare equal come out in their input order.
+///
+/// # Examples
+/// ```
+/// use natural_sort::NaturalPartialSorter;
+/// let mut sorter = NaturalPartialSorter::new(vec!["z10", "z9", "z101", "z3"]);
+/// assert_eq!(sorter.take_sorted(2), vec!["z3", "z9"]);
+/// assert_eq!(sorter.next(), Some("z10"));
+/// assert_eq!(sorter.len(), 1);
+/// ```
+pub struct NaturalPartialSorter<T> {
+    heap: BinaryHeap<Reverse<(Natural<T>, usize)>>,
+}
+
+impl<T: AsRef<str>> NaturalPartialSorter<T> {
+    /// Creates a sorter over the given values
+    ///
+    /// # Arguments
+    ///
+    /// * `vals` - Anything that iterates over string-like values
+    pub fn new<I: IntoIterator<Item = T>>(vals: I) -> Self {
+        let heap: Vec<_> = vals
+            .into_iter()
+            .enumerate()
+            .map(|(i, val)| Reverse((Natural(val), i)))
+            .collect();
+
+        NaturalPartialSorter {
+            heap: BinaryHeap::from(heap),
+        }
+    }
+
+    /// Returns the next value without removing it
+    pub fn peek(&self) -> Option<&T> {
+        self.heap.peek().map(|Reverse((val, _))| &val.0)
+    }
+
+    /// Removes and returns up to `n` of the smallest remaining values, in order
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The maximum number of values to return
+    pub fn take_sorted(&mut self, n: usize) -> Vec<T> {
+        self.by_ref().take(n).collect()
+    }
+
+    /// Returns the number of values that haven't been yielded yet
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns true if every value has been yielded
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+}
+
+impl<T: AsRef<str>> Iterator for NaturalPartialSorter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.heap.pop().map(|Reverse((val, _))| val.0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.heap.len(), Some(self.heap.len()))
+    }
+}
+
+impl<T: AsRef<str>> ExactSizeIterator for NaturalPartialSorter<T> {}
+
+impl<T: AsRef<str>> FromIterator<T> for NaturalPartialSorter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(vals: I) -> Self {
+        NaturalPartialSorter::new(vals)
+    }
+}
+
+#[test]
+fn test_partial_sorter() {
+    let list = vec![
+        "z10b", "b23g", "z999", "z10a", "x12z34", "x12z101", "z9", "z3", "z101", "z5",
+    ];
+    let expected = vec![
+        "b23g", "x12z34", "x12z101", "z3", "z5", "z9", "z10a", "z10b", "z101", "z999",
+    ];
+
+    let mut sorter = NaturalPartialSorter::new(list.clone());
+    assert_eq!(sorter.peek(), Some(&"b23g"));
+    assert_eq!(sorter.take_sorted(3), &expected[..3]);
+    assert_eq!(sorter.len(), 7);
+    assert_eq!(sorter.collect::<Vec<_>>(), &expected[3..]);
+
+    let sorter: NaturalPartialSorter<&str> = vec!["a01", "a1", "a001"].into_iter().collect();
+    assert_eq!(sorter.collect::<Vec<_>>(), vec!["a01", "a1", "a001"]);
+}
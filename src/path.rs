@@ -0,0 +1,606 @@
+use std::cmp::Ordering;
+use std::ffi::OsStr;
+use std::fs::{self, DirEntry};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::natural_cmp;
+
+/// Where hidden files, whose names start with a `.`, are placed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum HiddenFiles {
+    /// Sorted among the other files as if the leading `.` wasn't there
+    #[default]
+    Mixed,
+    /// Before every other file
+    First,
+    /// After every other file
+    Last,
+}
+
+/// Configures how directory listings and paths are sorted
+///
+/// File names are compared in a natural way. Names that are naturally equal
+/// (`"a01"` and `"a1"`) are ordered by their raw bytes so listings are deterministic.
+/// Directories are detected from the file system when it's available, and
+/// paths that can't be inspected count as files.
+///
+/// # Examples
+/// ```no_run
+/// use natural_sort::{HiddenFiles, PathSortOptions};
+/// let paths = PathSortOptions::new()
+///     .dirs_first(true)
+///     .hidden(HiddenFiles::Last)
+///     .read_dir(".")
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PathSortOptions {
+    dirs_first: bool,
+    hidden: HiddenFiles,
+    group_by_extension: bool,
+    windows: bool,
+}
+
+impl PathSortOptions {
+    /// Creates the default options, which mix directories, files and hidden files
+    pub fn new() -> Self {
+        PathSortOptions::default()
+    }
+
+    /// Places directories before files
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether directories come first
+    pub fn dirs_first(mut self, enabled: bool) -> Self {
+        self.dirs_first = enabled;
+        self
+    }
+
+    /// Sets where hidden files are placed
+    ///
+    /// # Arguments
+    ///
+    /// * `hidden` - The placement of hidden files
+    pub fn hidden(mut self, hidden: HiddenFiles) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Groups files by extension, comparing the extension before the rest of the name
+    ///
+    /// Names without an extension come first.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to group by extension
+    ///
+    /// # Examples
+    /// ```
+    /// use natural_sort::PathSortOptions;
+    /// let mut paths = vec!["b.txt", "a10.rs", "a2.txt", "Makefile", "a9.rs"];
+    /// PathSortOptions::new().group_by_extension(true).sort_paths(&mut paths);
+    /// assert_eq!(paths, vec!["Makefile", "a9.rs", "a10.rs", "a2.txt", "b.txt"]);
+    /// ```
+    pub fn group_by_extension(mut self, enabled: bool) -> Self {
+        self.group_by_extension = enabled;
+        self
+    }
+
+    /// Compares paths using Windows rules, on any platform
+    ///
+    /// Both `\\` and `/` separate components, verbatim prefixes are removed so `\\?\C:\x` equals
+    /// `C:\x` and `\\?\UNC\server\share` equals `\\server\share`, and every component is compared
+    /// case-insensitively. Relative paths sort first, then rooted paths, then drive letters in
+    /// alphabetical order, then UNC shares.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to use Windows rules
+    ///
+    /// # Examples
+    /// ```
+    /// use natural_sort::PathSortOptions;
+    /// let mut paths = vec![r"\\?\d:\File2", r"c:\dir\file10", r"\\server\share\a", r"C:\Dir\FILE9"];
+    /// PathSortOptions::new().windows(true).sort_paths(&mut paths);
+    /// assert_eq!(paths, vec![r"C:\Dir\FILE9", r"c:\dir\file10", r"\\?\d:\File2", r"\\server\share\a"]);
+    /// ```
+    pub fn windows(mut self, enabled: bool) -> Self {
+        self.windows = enabled;
+        self
+    }
+
+    /// Compares two directory entries
+    ///
+    /// Whether an entry is a directory is looked up on every call, so prefer
+    /// [`PathSortOptions::read_dir`] for whole listings.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The left hand side
+    /// * `b` - The right hand side
+    pub fn compare_entries(&self, a: &DirEntry, b: &DirEntry) -> Ordering {
+        self.compare_names(&a.file_name(), is_dir(a), &b.file_name(), is_dir(b))
+    }
+
+    /// Lists a directory, sorted using these options
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The directory to list
+    pub fn read_dir<P: AsRef<Path>>(&self, path: P) -> io::Result<Vec<PathBuf>> {
+        let mut entries = fs::read_dir(path)?
+            .map(|entry| entry.map(|e| (e.path(), e.file_name(), is_dir(&e))))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        entries.sort_by(|(_, a, a_dir), (_, b, b_dir)| self.compare_names(a, *a_dir, b, *b_dir));
+
+        Ok(entries.into_iter().map(|(path, _, _)| path).collect())
+    }
+
+    /// Compares two paths
+    ///
+    /// Parent directories are compared component by component, and a directory's own
+    /// entries sort before anything in its subdirectories. The file names are then compared
+    /// using these options. Looks up whether the paths are directories if `dirs_first` is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The left hand side
+    /// * `b` - The right hand side
+    pub fn compare_paths(&self, a: &Path, b: &Path) -> Ordering {
+        let (a_dir, b_dir) = match self.dirs_first {
+            true => (a.is_dir(), b.is_dir()),
+            false => (false, false),
+        };
+        self.compare_full(a, a_dir, b, b_dir)
+    }
+
+    /// Sorts paths using these options
+    ///
+    /// Directories are looked up once per path, and only if `dirs_first` is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `paths` - A vector of paths
+    pub fn sort_paths<P: AsRef<Path>>(&self, paths: &mut Vec<P>) {
+        let mut entries: Vec<(bool, P)> = paths
+            .drain(..)
+            .map(|p| (self.dirs_first && p.as_ref().is_dir(), p))
+            .collect();
+
+        entries.sort_by(|(a_dir, a), (b_dir, b)| {
+            self.compare_full(a.as_ref(), *a_dir, b.as_ref(), *b_dir)
+        });
+
+        paths.extend(entries.into_iter().map(|(_, p)| p));
+    }
+
+    fn compare_full(&self, a: &Path, a_dir: bool, b: &Path, b_dir: bool) -> Ordering {
+        if self.windows {
+            let (a_str, b_str) = (
+                a.as_os_str().to_string_lossy(),
+                b.as_os_str().to_string_lossy(),
+            );
+            return self.compare_windows(&a_str, a_dir, &b_str, b_dir);
+        }
+
+        let empty = Path::new("");
+        let by_parent =
+            compare_components(a.parent().unwrap_or(empty), b.parent().unwrap_or(empty));
+
+        by_parent.then_with(|| {
+            let a_name = a.file_name().unwrap_or(a.as_os_str());
+            let b_name = b.file_name().unwrap_or(b.as_os_str());
+            self.compare_names(a_name, a_dir, b_name, b_dir)
+        })
+    }
+
+    fn compare_windows(&self, a: &str, a_dir: bool, b: &str, b_dir: bool) -> Ordering {
+        let (a_prefix, mut a_parts) = WindowsPrefix::parse(a);
+        let (b_prefix, mut b_parts) = WindowsPrefix::parse(b);
+        let a_name = a_parts.pop().unwrap_or("");
+        let b_name = b_parts.pop().unwrap_or("");
+
+        let by_prefix =
+            a_prefix
+                .rank()
+                .cmp(&b_prefix.rank())
+                .then_with(|| match (a_prefix, b_prefix) {
+                    (WindowsPrefix::Drive(x), WindowsPrefix::Drive(y)) => x.cmp(&y),
+                    (WindowsPrefix::Unc(x, x_share), WindowsPrefix::Unc(y, y_share)) => self
+                        .natural(x, y)
+                        .then_with(|| self.natural(x_share, y_share)),
+                    _ => Ordering::Equal,
+                });
+
+        let by_parent = || {
+            let mut b_iter = b_parts.iter();
+            for x in &a_parts {
+                match b_iter.next() {
+                    None => return Ordering::Greater,
+                    Some(y) => match self
+                        .natural(x, y)
+                        .then_with(|| x.to_lowercase().cmp(&y.to_lowercase()))
+                    {
+                        Ordering::Equal => {}
+                        ord => return ord,
+                    },
+                }
+            }
+            a_parts.len().cmp(&b_parts.len())
+        };
+
+        by_prefix
+            .then_with(by_parent)
+            .then_with(|| self.compare_names(OsStr::new(a_name), a_dir, OsStr::new(b_name), b_dir))
+    }
+
+    fn natural(&self, a: &str, b: &str) -> Ordering {
+        match self.windows {
+            true => natural_cmp(&a.to_lowercase(), &b.to_lowercase()),
+            false => natural_cmp(a, b),
+        }
+    }
+
+    fn compare_names(&self, a: &OsStr, a_dir: bool, b: &OsStr, b_dir: bool) -> Ordering {
+        if self.dirs_first && a_dir != b_dir {
+            return b_dir.cmp(&a_dir);
+        }
+
+        let a_name = a.to_string_lossy();
+        let b_name = b.to_string_lossy();
+        let a_hidden = a_name.starts_with('.');
+        let b_hidden = b_name.starts_with('.');
+
+        let by_placement = match self.hidden {
+            HiddenFiles::Mixed => Ordering::Equal,
+            HiddenFiles::First => b_hidden.cmp(&a_hidden),
+            HiddenFiles::Last => a_hidden.cmp(&b_hidden),
+        };
+
+        let (a_key, b_key) = match self.hidden {
+            HiddenFiles::Mixed => (
+                a_name.strip_prefix('.').unwrap_or(&a_name),
+                b_name.strip_prefix('.').unwrap_or(&b_name),
+            ),
+            _ => (&*a_name, &*b_name),
+        };
+
+        let by_name = || match self.group_by_extension {
+            true => {
+                let (a_stem, a_ext) = split_extension(a_key);
+                let (b_stem, b_ext) = split_extension(b_key);
+                self.natural(a_ext, b_ext)
+                    .then_with(|| self.natural(a_stem, b_stem))
+            }
+            false => self.natural(a_key, b_key),
+        };
+
+        by_placement.then_with(by_name).then_with(|| a.cmp(b))
+    }
+}
+
+/// The start of a Windows path, once any verbatim `\\?\` prefix is removed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum WindowsPrefix<'a> {
+    Relative,
+    Rooted,
+    Drive(char),
+    Unc(&'a str, &'a str),
+}
+
+impl<'a> WindowsPrefix<'a> {
+    /// Splits a path into its prefix and the remaining components
+    fn parse(path: &'a str) -> (WindowsPrefix<'a>, Vec<&'a str>) {
+        let is_sep = |c: char| c == '\\' || c == '/';
+        let components = |rest: &'a str| -> Vec<&'a str> {
+            rest.split(is_sep)
+                .filter(|c| !c.is_empty() && *c != ".")
+                .collect()
+        };
+
+        let unc = |rest: &'a str| {
+            let mut parts = components(rest);
+            let share = if parts.len() > 1 { parts.remove(1) } else { "" };
+            let server = if parts.is_empty() {
+                ""
+            } else {
+                parts.remove(0)
+            };
+            (WindowsPrefix::Unc(server, share), parts)
+        };
+
+        for verbatim in [r"\\?\UNC\", r"\\.\UNC\"] {
+            if let Some(rest) = path.strip_prefix(verbatim) {
+                return unc(rest);
+            }
+        }
+        let path = path
+            .strip_prefix(r"\\?\")
+            .or_else(|| path.strip_prefix(r"\\.\"))
+            .unwrap_or(path);
+
+        let mut chars = path.chars();
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some(x), Some(y), _) if is_sep(x) && is_sep(y) => unc(&path[2..]),
+            (Some(letter), Some(':'), _) if letter.is_ascii_alphabetic() => (
+                WindowsPrefix::Drive(letter.to_ascii_uppercase()),
+                components(&path[2..]),
+            ),
+            (Some(x), _, _) if is_sep(x) => (WindowsPrefix::Rooted, components(path)),
+            _ => (WindowsPrefix::Relative, components(path)),
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            WindowsPrefix::Relative => 0,
+            WindowsPrefix::Rooted => 1,
+            WindowsPrefix::Drive(_) => 2,
+            WindowsPrefix::Unc(_, _) => 3,
+        }
+    }
+}
+
+fn compare_components(a: &Path, b: &Path) -> Ordering {
+    let mut a = a.components();
+    let mut b = b.components();
+
+    loop {
+        match (a.next(), b.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) => {
+                let (x, y) = (x.as_os_str(), y.as_os_str());
+                // Naturally equal components like `a01` and `a1` must not interleave their children
+                match natural_cmp(&x.to_string_lossy(), &y.to_string_lossy()).then_with(|| x.cmp(y))
+                {
+                    Ordering::Equal => {}
+                    ord => return ord,
+                }
+            }
+        }
+    }
+}
+
+fn split_extension(name: &str) -> (&str, &str) {
+    match name.rfind('.') {
+        Some(i) if i > 0 => (&name[..i], &name[i + 1..]),
+        _ => (name, ""),
+    }
+}
+
+fn is_dir(entry: &DirEntry) -> bool {
+    match entry.file_type() {
+        Ok(t) if t.is_symlink() => entry.path().is_dir(),
+        Ok(t) => t.is_dir(),
+        Err(_) => false,
+    }
+}
+
+/// Compares two directory entries by file name in a natural way
+///
+/// Names that compare naturally equal, like `"a01"` and `"a1"` or two names
+/// that are only different in their invalid UTF-8, are ordered by their raw
+/// bytes, so sorting entries always gives the same order.
+///
+/// # Arguments
+///
+/// * `a` - The left hand side
+/// * `b` - The right hand side
+pub fn natural_cmp_dir_entry(a: &DirEntry, b: &DirEntry) -> Ordering {
+    let (a, b) = (a.file_name(), b.file_name());
+    natural_cmp(&a.to_string_lossy(), &b.to_string_lossy()).then_with(|| a.cmp(&b))
+}
+
+/// Lists a directory in natural order
+///
+/// Shorthand for `PathSortOptions::new().read_dir(path)`.
+///
+/// # Arguments
+///
+/// * `path` - The directory to list
+///
+/// # Examples
+/// ```no_run
+/// use natural_sort::read_dir_sorted;
+/// for path in read_dir_sorted(".").unwrap() {
+///     println!("{}", path.display());
+/// }
+/// ```
+pub fn read_dir_sorted<P: AsRef<Path>>(path: P) -> io::Result<Vec<PathBuf>> {
+    PathSortOptions::new().read_dir(path)
+}
+
+#[test]
+fn test_read_dir_sorted() {
+    let dir = std::env::temp_dir().join(format!("natural-sort-read-dir-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    for name in ["file10", "file9", ".hidden", "dir2", ".a"] {
+        fs::write(dir.join(name), "").unwrap();
+    }
+    fs::create_dir_all(dir.join("dir10")).unwrap();
+    fs::create_dir_all(dir.join(".git")).unwrap();
+
+    let names = |options: PathSortOptions| -> Vec<String> {
+        options
+            .read_dir(&dir)
+            .unwrap()
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+            .collect()
+    };
+
+    let plain = names(PathSortOptions::new());
+    let dirs_first = names(PathSortOptions::new().dirs_first(true));
+    let hidden_last = names(PathSortOptions::new().hidden(HiddenFiles::Last));
+    let hidden_first = names(
+        PathSortOptions::new()
+            .dirs_first(true)
+            .hidden(HiddenFiles::First),
+    );
+    let sorted = read_dir_sorted(&dir).unwrap();
+
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(
+        plain,
+        vec![".a", "dir2", "dir10", "file9", "file10", ".git", ".hidden"]
+    );
+    assert_eq!(
+        dirs_first,
+        vec!["dir10", ".git", ".a", "dir2", "file9", "file10", ".hidden"]
+    );
+    assert_eq!(
+        hidden_last,
+        vec!["dir2", "dir10", "file9", "file10", ".a", ".git", ".hidden"]
+    );
+    assert_eq!(
+        hidden_first,
+        vec![".git", "dir10", ".a", ".hidden", "dir2", "file9", "file10"]
+    );
+    assert_eq!(sorted.len(), 7);
+}
+
+#[test]
+fn test_natural_cmp_dir_entry() {
+    let dir = std::env::temp_dir().join(format!("natural-sort-dir-entry-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    for name in ["a1", "a2", "a01", "a001", "a10"] {
+        fs::write(dir.join(name), "").unwrap();
+    }
+
+    let mut entries: Vec<DirEntry> = fs::read_dir(&dir).unwrap().map(Result::unwrap).collect();
+    entries.sort_by(natural_cmp_dir_entry);
+    let names: Vec<String> = entries
+        .iter()
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect();
+    let same = natural_cmp_dir_entry(&entries[0], &entries[0]);
+
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(names, vec!["a001", "a01", "a1", "a2", "a10"]);
+    assert_eq!(same, Ordering::Equal);
+}
+
+#[test]
+fn test_sort_paths() {
+    let mut paths = vec![
+        "src/b10.rs",
+        "src/b9.rs",
+        "src/a/z.rs",
+        "README.md",
+        "src/a2.txt",
+        "Cargo.toml",
+        ".gitignore",
+        "src/Makefile",
+    ];
+
+    PathSortOptions::new().sort_paths(&mut paths);
+    assert_eq!(
+        paths,
+        vec![
+            "Cargo.toml",
+            "README.md",
+            ".gitignore",
+            "src/Makefile",
+            "src/a2.txt",
+            "src/b9.rs",
+            "src/b10.rs",
+            "src/a/z.rs",
+        ]
+    );
+
+    PathSortOptions::new()
+        .group_by_extension(true)
+        .hidden(HiddenFiles::First)
+        .sort_paths(&mut paths);
+    assert_eq!(
+        paths,
+        vec![
+            ".gitignore",
+            "README.md",
+            "Cargo.toml",
+            "src/Makefile",
+            "src/b9.rs",
+            "src/b10.rs",
+            "src/a2.txt",
+            "src/a/z.rs",
+        ]
+    );
+
+    let options = PathSortOptions::new();
+    assert_eq!(
+        options.compare_paths(Path::new("a/b2/x"), Path::new("a/b10/x")),
+        Ordering::Less
+    );
+
+    let mut paths = vec!["a01/a", "a1/b", "a01/c"];
+    options.sort_paths(&mut paths);
+    assert_eq!(paths, vec!["a01/a", "a01/c", "a1/b"]);
+
+    let windows = PathSortOptions::new().windows(true);
+    let mut paths = vec![r"C:\a01\a", r"C:\a1\b", r"C:\a01\c"];
+    windows.sort_paths(&mut paths);
+    assert_eq!(paths, vec![r"C:\a01\a", r"C:\a01\c", r"C:\a1\b"]);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+
+        let path = |bytes: &[u8]| Path::new(OsStr::from_bytes(bytes)).to_path_buf();
+        let mut paths = vec![path(b"\xff/a"), path(b"\xfe/b"), path(b"\xff/c")];
+        options.sort_paths(&mut paths);
+        assert_eq!(
+            paths,
+            vec![path(b"\xfe/b"), path(b"\xff/a"), path(b"\xff/c")]
+        );
+    }
+}
+
+#[test]
+fn test_windows_paths() {
+    assert_eq!(
+        WindowsPrefix::parse(r"\\?\C:\Users\me"),
+        (WindowsPrefix::Drive('C'), vec!["Users", "me"])
+    );
+    assert_eq!(
+        WindowsPrefix::parse(r"\\?\UNC\server\share\dir"),
+        (WindowsPrefix::Unc("server", "share"), vec!["dir"])
+    );
+    assert_eq!(
+        WindowsPrefix::parse(r"\\server\share"),
+        (WindowsPrefix::Unc("server", "share"), vec![])
+    );
+    assert_eq!(
+        WindowsPrefix::parse(r"d:/a/./b"),
+        (WindowsPrefix::Drive('D'), vec!["a", "b"])
+    );
+    assert_eq!(
+        WindowsPrefix::parse(r"\temp"),
+        (WindowsPrefix::Rooted, vec!["temp"])
+    );
+
+    let options = PathSortOptions::new().windows(true);
+    let cmp = |a: &str, b: &str| options.compare_paths(Path::new(a), Path::new(b));
+    assert_eq!(
+        cmp(r"\\?\C:\Dir\File10", r"c:\dir\file9"),
+        Ordering::Greater
+    );
+    assert_eq!(cmp(r"C:\Dir2\x", r"c:\dir10\a"), Ordering::Less);
+    assert_eq!(
+        cmp(r"\\?\UNC\srv\share\a", r"\\SRV\Share\a"),
+        Ordering::Equal
+    );
+    assert_eq!(
+        cmp(r"\\?\UNC\srv\share\a", r"\\SRV\Share\b"),
+        Ordering::Less
+    );
+    assert_eq!(cmp(r"Z:\a", r"\\srv\share\a"), Ordering::Less);
+    assert_eq!(cmp(r"relative\a", r"C:\a"), Ordering::Less);
+    assert_eq!(cmp(r"C:\a\file", r"C:\a\b\file"), Ordering::Less);
+}
@@ -0,0 +1,128 @@
+//! Python bindings, enabled with the `pyo3` feature
+//!
+//! The module is called `natural_sort` and exposes `natural_key(s)`, for use as
+//! `sorted(names, key=natural_key)`, and `natural_sort(list)` which returns a sorted copy.
+//!
+//! Build the extension with `cargo rustc --release --features pyo3 --crate-type cdylib` and
+//! rename the resulting library to `natural_sort.so` (`natural_sort.pyd` on Windows).
+
+use pyo3::prelude::*;
+
+use crate::{natural_cmp, numeric, scan};
+
+/// Returns a key that orders like `natural_cmp` under Python's comparison rules
+///
+/// The key is a list of `(text, digit_count, digits)` tuples, one per text run
+/// and the number after it. Digits are kept as ASCII text without their leading
+/// zeros, so numbers of any length and in any script compare like `natural_cmp`
+/// compares them: by digit count first, then digit by digit.
+/// The last tuple has no number and a count of `-1`, so it sorts before any real number.
+#[pyfunction]
+pub fn natural_key(s: &str) -> Vec<(String, i64, String)> {
+    let mut key = vec![];
+    let mut rest = s;
+
+    loop {
+        let (text, tail) = rest.split_at(scan::find_numeric(rest).unwrap_or(rest.len()));
+        let (num, tail) = tail.split_at(scan::find_non_numeric(tail).unwrap_or(tail.len()));
+        if num.is_empty() {
+            key.push((String::from(text), -1, String::new()));
+            return key;
+        }
+
+        let digits = numeric::trim_zeros(num);
+        let mut ascii = String::with_capacity(digits.len());
+        numeric::push_ascii_digits(&mut ascii, digits);
+        key.push((String::from(text), digits.chars().count() as i64, ascii));
+        rest = tail;
+    }
+}
+
+/// Returns a naturally sorted copy of a list of strings
+#[pyfunction]
+#[pyo3(name = "natural_sort")]
+pub fn sort(vals: Vec<String>) -> Vec<String> {
+    let mut vals = vals;
+    vals.sort_by(|a, b| natural_cmp(a, b));
+    vals
+}
+
+#[pymodule]
+#[pyo3(name = "natural_sort")]
+fn natural_sort_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(natural_key, m)?)?;
+    m.add_function(wrap_pyfunction!(sort, m)?)?;
+    Ok(())
+}
+
+#[test]
+fn test_natural_key() {
+    let mut list = vec![
+        "z10a", "b23g", "z999", "z10", "x12z34", "x12z101", "z9", "z3", "z101", "z5",
+    ];
+    list.sort_by_key(|s| natural_key(s));
+
+    assert_eq!(
+        list,
+        vec!["b23g", "x12z34", "x12z101", "z3", "z5", "z9", "z10", "z10a", "z101", "z999"]
+    );
+    assert_eq!(natural_key("a01"), natural_key("a1"));
+    assert!(natural_key("asdf") < natural_key("asdf1"));
+    assert!(natural_key("z10") < natural_key("z10a"));
+    assert_eq!(
+        natural_key("12ab"),
+        vec![("".into(), 2, "12".into()), ("ab".into(), -1, "".into())]
+    );
+}
+
+#[test]
+fn test_natural_key_order() {
+    let long = "1".repeat(40);
+    let longer = format!("2{}", "0".repeat(39));
+    let corpus = [
+        "",
+        "0",
+        "00",
+        "1",
+        "01",
+        "9",
+        "10",
+        "a",
+        "a-",
+        "a1",
+        "a01",
+        "a1b",
+        "a2",
+        "a10",
+        "a1-",
+        "a١",
+        "a٢",
+        "a١٠",
+        "a٠٣",
+        "a٣",
+        "a3",
+        "a12",
+        "z10a",
+        "x12z34",
+        "x12z101",
+        "é2",
+        "é10",
+        "ab",
+        &long,
+        &longer,
+        "a340282366920938463463374607431768211456",
+        "a340282366920938463463374607431768211457",
+    ];
+
+    for a in corpus {
+        for b in corpus {
+            assert_eq!(
+                natural_key(a).cmp(&natural_key(b)),
+                natural_cmp(a, b),
+                "{:?} {:?}",
+                a,
+                b
+            );
+        }
+    }
+}
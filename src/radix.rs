@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+
+use crate::{natural_cmp, segments, Segment};
+
+/// Indices of the values sharing one leading text, split by whether a number follows it
+///
+/// Numbers that don't fit a `u64` can't be radix sorted, a bucket holding any
+/// of those is comparison sorted instead.
+#[derive(Default)]
+struct Bucket {
+    plain: Vec<usize>,
+    numbered: Vec<(u64, usize)>,
+    fallback: bool,
+}
+
+/// Sorts naturally by bucketing on the leading text and radix sorting the first number
+///
+/// Gives the same order as a stable natural sort, but runs in close to linear time
+/// when most values follow one pattern, like `shard-<n>-part-<m>`. Values that tie on
+/// both the leading text and the first number fall back to a comparison sort among themselves.
+///
+/// # Arguments
+///
+/// * `vals` - A vector of string-like values
+///
+/// # Examples
+/// ```
+/// use natural_sort::natural_sort_radix;
+/// let mut list = vec!["shard-10-part-2", "shard-9-part-1", "shard-10-part-1", "log"];
+/// natural_sort_radix(&mut list);
+/// assert_eq!(list, vec!["log", "shard-9-part-1", "shard-10-part-1", "shard-10-part-2"]);
+/// ```
+pub fn natural_sort_radix<T: AsRef<str>>(vals: &mut Vec<T>) {
+    let mut buckets: BTreeMap<String, Bucket> = BTreeMap::new();
+
+    for (i, val) in vals.iter().enumerate() {
+        let mut parts = segments(val.as_ref()).peekable();
+        let alpha = match parts.next_if(|part| !part.is_number()) {
+            Some(part) => part.as_str(),
+            None => "",
+        };
+        let bucket = buckets.entry(String::from(alpha)).or_default();
+        match parts.next() {
+            Some(Segment::Number { value, .. }) => match u64::try_from(value) {
+                Ok(n) => bucket.numbered.push((n, i)),
+                _ => {
+                    bucket.numbered.push((u64::MAX, i));
+                    bucket.fallback = true;
+                }
+            },
+            _ => bucket.plain.push(i),
+        }
+    }
+
+    let mut order = Vec::with_capacity(vals.len());
+    for mut bucket in buckets.into_values() {
+        order.extend(bucket.plain);
+
+        if bucket.fallback {
+            bucket
+                .numbered
+                .sort_by(|a, b| natural_cmp(vals[a.1].as_ref(), vals[b.1].as_ref()));
+            order.extend(bucket.numbered.iter().map(|(_, i)| *i));
+            continue;
+        }
+
+        radix_sort(&mut bucket.numbered);
+        for run in bucket.numbered.chunk_by_mut(|a, b| a.0 == b.0) {
+            if run.len() > 1 {
+                run.sort_by(|a, b| natural_cmp(vals[a.1].as_ref(), vals[b.1].as_ref()));
+            }
+            order.extend(run.iter().map(|(_, i)| *i));
+        }
+    }
+
+    let mut taken: Vec<Option<T>> = vals.drain(..).map(Some).collect();
+    vals.extend(order.into_iter().map(|i| taken[i].take().unwrap()));
+}
+
+/// Stable least significant digit radix sort on the numeric key, one byte per pass
+fn radix_sort(vals: &mut Vec<(u64, usize)>) {
+    let max = vals.iter().map(|(n, _)| *n).max().unwrap_or(0);
+    let passes = (u64::BITS - max.leading_zeros()).div_ceil(8);
+
+    let mut scratch = vec![(0, 0); vals.len()];
+    for pass in 0..passes {
+        let shift = pass * 8;
+        let mut counts = [0usize; 257];
+        for (n, _) in vals.iter() {
+            counts[((n >> shift) & 0xff) as usize + 1] += 1;
+        }
+        for i in 1..counts.len() {
+            counts[i] += counts[i - 1];
+        }
+        for val in vals.iter() {
+            let digit = ((val.0 >> shift) & 0xff) as usize;
+            scratch[counts[digit]] = *val;
+            counts[digit] += 1;
+        }
+        std::mem::swap(vals, &mut scratch);
+    }
+}
+
+#[test]
+fn test_natural_sort_radix() {
+    let mut list = vec![
+        "z10a", "b23g", "z999", "z10", "x12z34", "x12z101", "z9", "z3", "z101", "z5", "z", "z0300",
+        "z300", "z256", "z65536", "a",
+    ];
+    let expected = vec![
+        "a", "b23g", "x12z34", "x12z101", "z", "z3", "z5", "z9", "z10", "z10a", "z101", "z256",
+        "z0300", "z300", "z999", "z65536",
+    ];
+
+    natural_sort_radix(&mut list);
+
+    assert_eq!(list, expected);
+}
+
+#[test]
+fn test_natural_sort_radix_wide_numbers() {
+    let mut list = vec![
+        "n99999999999999999999999",
+        "n10",
+        "n٣",
+        "n٠٣",
+        "n3",
+        "n١٠",
+        "n18446744073709551615",
+        "n2",
+    ];
+    let mut expected = list.clone();
+    expected.sort_by(|a, b| natural_cmp(a, b));
+
+    natural_sort_radix(&mut list);
+
+    assert_eq!(list, expected);
+    assert_eq!(list[0], "n2");
+}
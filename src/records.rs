@@ -0,0 +1,76 @@
+use std::io::{self, BufRead, Write};
+
+use crate::natural_cmp;
+
+/// Reads records ending in `separator` from `reader` and writes them to `writer` in natural order
+///
+/// Records are kept as raw bytes, so arbitrary file names survive the round
+/// trip. They are compared after lossy UTF-8 decoding. Every record is written
+/// followed by `separator`, including a last one that lacked it. The sort is
+/// stable. Use `b'\n'` for lines and `b'\0'` for the output of `find -print0`,
+/// mirroring `sort -z`.
+///
+/// # Arguments
+///
+/// * `reader` - The input
+/// * `writer` - The output
+/// * `separator` - The byte that ends each record
+///
+/// # Examples
+/// ```
+/// use natural_sort::natural_sort_records;
+/// let input = b"file10\0new\nline2\0file9";
+/// let mut output = vec![];
+/// natural_sort_records(&input[..], &mut output, b'\0').unwrap();
+/// assert_eq!(output, b"file9\0file10\0new\nline2\0");
+/// ```
+pub fn natural_sort_records<R: BufRead, W: Write>(
+    mut reader: R,
+    mut writer: W,
+    separator: u8,
+) -> io::Result<()> {
+    let mut records = vec![];
+    loop {
+        let mut record = vec![];
+        if reader.read_until(separator, &mut record)? == 0 {
+            break;
+        }
+        if record.last() == Some(&separator) {
+            record.pop();
+        }
+        records.push((String::from_utf8_lossy(&record).into_owned(), record));
+    }
+
+    records.sort_by(|(a, _), (b, _)| natural_cmp(a, b));
+
+    for (_, record) in &records {
+        writer.write_all(record)?;
+        writer.write_all(&[separator])?;
+    }
+    writer.flush()
+}
+
+/// Sorts newline separated lines, like [`natural_sort_records`] with `b'\n'`
+///
+/// # Arguments
+///
+/// * `reader` - The input
+/// * `writer` - The output
+pub fn natural_sort_lines<R: BufRead, W: Write>(reader: R, writer: W) -> io::Result<()> {
+    natural_sort_records(reader, writer, b'\n')
+}
+
+#[test]
+fn test_natural_sort_records() {
+    let mut output = vec![];
+    natural_sort_lines(&b"z10\nz9\n\nz101\nx12z34"[..], &mut output).unwrap();
+    assert_eq!(output, b"\nx12z34\nz9\nz10\nz101\n");
+
+    let mut output = vec![];
+    natural_sort_records(&b"b\xff2\0b\xff10\0a\n1\0"[..], &mut output, 0).unwrap();
+    assert_eq!(output, b"a\n1\0b\xff2\0b\xff10\0");
+
+    let mut output = vec![];
+    natural_sort_records(&b""[..], &mut output, 0).unwrap();
+    assert!(output.is_empty());
+}
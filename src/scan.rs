@@ -0,0 +1,120 @@
+//! Finds the boundaries of numeric runs
+//!
+//! With the `simd` feature ASCII input is scanned eight bytes at a time
+//! (SIMD within a register), falling back to the scalar search as soon as a
+//! non-ASCII byte shows up, since those may still be numeric.
+
+/// Returns whether `c` is a decimal digit, in any script
+///
+/// Numeric characters with a value of their own, like `'½'`, `'²'` or `'③'`,
+/// are not digits and count as text.
+pub(crate) fn is_digit(c: char) -> bool {
+    c.is_ascii_digit() || (!c.is_ascii() && crate::numeric::is_decimal(c))
+}
+
+/// Returns the byte index of the first numeric character
+pub(crate) fn find_numeric(s: &str) -> Option<usize> {
+    #[cfg(feature = "simd")]
+    {
+        let start = swar::skip(s.as_bytes(), swar::numeric_or_non_ascii);
+        s[start..].find(is_digit).map(|index| start + index)
+    }
+
+    #[cfg(not(feature = "simd"))]
+    s.find(is_digit)
+}
+
+/// Returns the byte index of the first non-numeric character
+pub(crate) fn find_non_numeric(s: &str) -> Option<usize> {
+    #[cfg(feature = "simd")]
+    {
+        let start = swar::skip(s.as_bytes(), swar::non_digit);
+        s[start..]
+            .find(|c: char| !is_digit(c))
+            .map(|index| start + index)
+    }
+
+    #[cfg(not(feature = "simd"))]
+    s.find(|c: char| !is_digit(c))
+}
+
+#[cfg(feature = "simd")]
+mod swar {
+    const LANES: usize = 8;
+    const ONES: u64 = u64::from_ne_bytes([0x01; LANES]);
+    const HIGH: u64 = ONES * 0x80;
+    const LOW: u64 = ONES * 0x7f;
+
+    /// Sets the high bit of every byte that is an ASCII digit
+    fn digits(word: u64) -> u64 {
+        let ascii = word & LOW;
+        let at_least_0 = ascii + ONES * (0x80 - b'0' as u64);
+        let above_9 = ascii + ONES * (0x80 - b'9' as u64 - 1);
+        at_least_0 & !above_9 & !word & HIGH
+    }
+
+    /// Sets the high bit of every byte that is an ASCII digit or not ASCII at all
+    pub(super) fn numeric_or_non_ascii(word: u64) -> u64 {
+        digits(word) | (word & HIGH)
+    }
+
+    /// Sets the high bit of every byte that isn't an ASCII digit
+    pub(super) fn non_digit(word: u64) -> u64 {
+        !digits(word) & HIGH
+    }
+
+    /// Returns the index of the first word containing a byte flagged by `mask`, or
+    /// the start of the unaligned tail, so the caller only has to scan from there
+    pub(super) fn skip(bytes: &[u8], mask: fn(u64) -> u64) -> usize {
+        let mut index = 0;
+
+        for chunk in bytes.chunks_exact(LANES) {
+            let word = u64::from_le_bytes(chunk.try_into().unwrap());
+            let found = mask(word);
+            if found != 0 {
+                return index + found.trailing_zeros() as usize / LANES;
+            }
+            index += LANES;
+        }
+
+        index
+    }
+}
+
+#[test]
+fn test_scan() {
+    let inputs = [
+        "",
+        "abc",
+        "12345678901234567890",
+        "abcdefghijklmnop1",
+        "abcdefgh/:0123456789:/xyz",
+        "z10a",
+        "x12z101",
+        "äöüäöüäöü١٢٣ab",
+        "abcdefg½12",
+        "12345678½abc",
+        "1234567٣abc",
+        "abcdefgh②³12",
+    ];
+
+    for input in inputs {
+        for start in input.char_indices().map(|(i, _)| i) {
+            let s = &input[start..];
+            assert_eq!(find_numeric(s), s.find(is_digit), "{}", s);
+            assert_eq!(find_non_numeric(s), s.find(|c: char| !is_digit(c)), "{}", s);
+        }
+    }
+}
+
+#[test]
+fn test_is_digit() {
+    assert!(is_digit('7'));
+    assert!(is_digit('٣'));
+    assert!(is_digit('७'));
+    assert!(!is_digit('½'));
+    assert!(!is_digit('²'));
+    assert!(!is_digit('③'));
+    assert!(!is_digit('Ⅻ'));
+    assert!(!is_digit('x'));
+}
@@ -0,0 +1,193 @@
+use crate::{numeric, scan};
+
+/// One piece of a string as seen by the natural comparison
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Segment<'a> {
+    /// A run of text without numbers
+    Alpha(&'a str),
+    /// A run of numeric characters
+    Number {
+        /// The characters as they appear in the string, including leading zeros
+        raw: &'a str,
+        /// The value of the run, in whatever script its digits are written,
+        /// saturating at `u128::MAX` if it doesn't fit
+        value: u128,
+    },
+}
+
+impl<'a> Segment<'a> {
+    /// Returns the text of the segment as it appears in the string
+    pub fn as_str(&self) -> &'a str {
+        match self {
+            Segment::Alpha(s) => s,
+            Segment::Number { raw, .. } => raw,
+        }
+    }
+
+    /// Returns whether this is a number
+    pub fn is_number(&self) -> bool {
+        matches!(self, Segment::Number { .. })
+    }
+}
+
+/// Splits a string into the text and number segments it is compared by
+///
+/// Segments alternate between text and numbers and joined together give back
+/// the original string.
+///
+/// # Arguments
+///
+/// * `s` - The string to split
+///
+/// # Examples
+/// ```
+/// use natural_sort::{segments, Segment};
+/// let parts: Vec<Segment> = segments("img007.png").collect();
+/// assert_eq!(
+///     parts,
+///     vec![
+///         Segment::Alpha("img"),
+///         Segment::Number { raw: "007", value: 7 },
+///         Segment::Alpha(".png"),
+///     ]
+/// );
+///
+/// let first = segments("shard-12-part-3").find_map(|s| match s {
+///     Segment::Number { value, .. } => Some(value),
+///     Segment::Alpha(_) => None,
+/// });
+/// assert_eq!(first, Some(12));
+/// ```
+pub fn segments(s: &str) -> impl Iterator<Item = Segment<'_>> {
+    let mut rest = s;
+
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        let numeric = scan::find_numeric(rest) == Some(0);
+        let end = match numeric {
+            true => scan::find_non_numeric(rest),
+            false => scan::find_numeric(rest),
+        }
+        .unwrap_or(rest.len());
+
+        let (segment, tail) = rest.split_at(end);
+        rest = tail;
+
+        Some(match numeric {
+            true => Segment::Number {
+                raw: segment,
+                value: digits_value(segment),
+            },
+            false => Segment::Alpha(segment),
+        })
+    })
+}
+
+/// Returns the longest run of whole segments every value starts with
+///
+/// Unlike a character prefix, this never cuts a number or a text run in two:
+/// `"img10"` and `"img12"` share `"img"`, not `"img1"`.
+///
+/// # Arguments
+///
+/// * `vals` - The values to compare, usually one group of a sorted list
+///
+/// # Examples
+/// ```
+/// use natural_sort::natural_common_prefix;
+/// assert_eq!(natural_common_prefix(&["img10.png", "img12.png"]), "img");
+/// assert_eq!(natural_common_prefix(&["file-1-a", "file-1-b"]), "file-1");
+/// ```
+pub fn natural_common_prefix<T: AsRef<str>>(vals: &[T]) -> &str {
+    let Some((first, others)) = vals.split_first() else {
+        return "";
+    };
+    let first = first.as_ref();
+
+    let mut len = first.len();
+    for other in others {
+        let shared = segments(first)
+            .zip(segments(other.as_ref()))
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.as_str().len())
+            .sum();
+        len = len.min(shared);
+    }
+
+    &first[..len]
+}
+
+pub(crate) fn digits_value(raw: &str) -> u128 {
+    raw.chars()
+        .try_fold(0u128, |value, c| {
+            let digit = numeric::decimal_value(c)?;
+            value.checked_mul(10)?.checked_add(u128::from(digit))
+        })
+        .unwrap_or(u128::MAX)
+}
+
+#[test]
+fn test_segments() {
+    assert_eq!(segments("").count(), 0);
+    assert_eq!(
+        segments("abc").collect::<Vec<_>>(),
+        vec![Segment::Alpha("abc")]
+    );
+    assert_eq!(
+        segments("12ab3").collect::<Vec<_>>(),
+        vec![
+            Segment::Number {
+                raw: "12",
+                value: 12
+            },
+            Segment::Alpha("ab"),
+            Segment::Number { raw: "3", value: 3 },
+        ]
+    );
+
+    let big = "v340282366920938463463374607431768211456";
+    let last = segments(big).last().unwrap();
+    assert_eq!(last.as_str(), &big[1..]);
+    assert_eq!(
+        last,
+        Segment::Number {
+            raw: &big[1..],
+            value: u128::MAX
+        }
+    );
+
+    let mixed = "x١٢y";
+    let joined: String = segments(mixed).map(|s| s.as_str()).collect();
+    assert_eq!(joined, mixed);
+    assert_eq!(
+        segments(mixed).nth(1),
+        Some(Segment::Number {
+            raw: "١٢",
+            value: 12
+        })
+    );
+    assert_eq!(
+        segments("a٠٣").nth(1),
+        Some(Segment::Number {
+            raw: "٠٣",
+            value: 3
+        })
+    );
+}
+
+#[test]
+fn test_natural_common_prefix() {
+    let empty: [&str; 0] = [];
+    assert_eq!(natural_common_prefix(&empty), "");
+    assert_eq!(natural_common_prefix(&["only10"]), "only10");
+    assert_eq!(natural_common_prefix(&["a1b2", "a1b2", "a1b3"]), "a1b");
+    assert_eq!(natural_common_prefix(&["a1", "a01"]), "a");
+    assert_eq!(natural_common_prefix(&["abc", "abd"]), "");
+    assert_eq!(
+        natural_common_prefix(&[String::from("v2.1"), String::from("v2.10")]),
+        "v2."
+    );
+}
@@ -0,0 +1,213 @@
+use std::cmp::Ordering;
+use std::ops::{Bound, RangeBounds};
+
+use crate::{compare, natural_cmp, segment};
+
+/// Finds where a value should be inserted to keep a naturally sorted slice sorted
+///
+/// Uses a binary search, so `vals` must already be in natural order.
+/// The returned index is after any values that compare equal to `item`.
+///
+/// # Arguments
+///
+/// * `vals` - A naturally sorted slice
+/// * `item` - The value to look up
+///
+/// # Examples
+/// ```
+/// use natural_sort::natural_insertion_point;
+/// let list = vec!["dev1", "dev2", "dev10"];
+/// assert_eq!(natural_insertion_point(&list, "dev3"), 2);
+/// ```
+pub fn natural_insertion_point<T: AsRef<str>>(vals: &[T], item: &str) -> usize {
+    vals.partition_point(|val| natural_cmp(val.as_ref(), item) != Ordering::Greater)
+}
+
+/// Inserts a value into a naturally sorted vector, keeping it sorted
+///
+/// Returns the index the value was inserted at.
+///
+/// # Arguments
+///
+/// * `vals` - A naturally sorted vector
+/// * `item` - The value to insert
+///
+/// # Examples
+/// ```
+/// use natural_sort::natural_insert;
+/// let mut list = vec!["dev1", "dev2", "dev10"];
+/// natural_insert(&mut list, "dev3");
+/// assert_eq!(list, vec!["dev1", "dev2", "dev3", "dev10"]);
+/// ```
+pub fn natural_insert<T: AsRef<str>>(vals: &mut Vec<T>, item: T) -> usize {
+    let index = natural_insertion_point(vals, item.as_ref());
+    vals.insert(index, item);
+    index
+}
+
+/// Returns the part of a naturally sorted slice that falls within a range
+///
+/// Uses two binary searches, so `vals` must already be in natural order.
+/// Bounds compare naturally as well, so `"build-100".."build-200"` includes
+/// `"build-0150"` but not `"build-200"`.
+///
+/// # Arguments
+///
+/// * `vals` - A naturally sorted slice
+/// * `range` - The bounds to look up, any range of string slices
+///
+/// # Examples
+/// ```
+/// use natural_sort::natural_range;
+/// let list = vec!["build-9", "build-100", "build-150", "build-200", "build-1000"];
+/// assert_eq!(natural_range(&list, "build-100".."build-200"), ["build-100", "build-150"]);
+/// assert_eq!(natural_range(&list, "build-100"..="build-200").len(), 3);
+/// assert_eq!(natural_range(&list, .."build-10"), ["build-9"]);
+/// ```
+pub fn natural_range<'a, 'b, T, R>(vals: &'a [T], range: R) -> &'a [T]
+where
+    T: AsRef<str>,
+    R: RangeBounds<&'b str>,
+{
+    let position = |bound: Bound<&&str>, inclusive_end: bool| match bound {
+        Bound::Unbounded => None,
+        Bound::Included(s) | Bound::Excluded(s) => {
+            let after = matches!(bound, Bound::Excluded(_)) ^ inclusive_end;
+            Some(
+                vals.partition_point(|val| match natural_cmp(val.as_ref(), s) {
+                    Ordering::Less => true,
+                    Ordering::Equal => after,
+                    Ordering::Greater => false,
+                }),
+            )
+        }
+    };
+
+    let start = position(range.start_bound(), false).unwrap_or(0);
+    let end = position(range.end_bound(), true).unwrap_or(vals.len());
+    &vals[start..end.max(start)]
+}
+
+/// Returns the `k` values of a naturally sorted slice closest to `query`
+///
+/// The window grows outwards from where `query` would be inserted, one value
+/// at a time, taking whichever neighbour is closer. Closeness is decided by the
+/// first number or text run where a value differs from `query`: a difference
+/// in a number is as far as the difference between the two numbers, and any
+/// difference in text is farther than that. Ties go to the left neighbour, so
+/// `"a4"` is closer to `"a3"` than to `"a10"` and `"node10"` picks `"node9"` over
+/// `"node11"`.
+///
+/// # Arguments
+///
+/// * `vals` - A naturally sorted slice
+/// * `query` - The value to look around
+/// * `k` - How many values to return at most
+///
+/// # Examples
+/// ```
+/// use natural_sort::natural_nearest;
+/// let list = vec!["node1", "node2", "node9", "node10", "node11", "node20"];
+/// assert_eq!(natural_nearest(&list, "node10", 2), ["node9", "node10"]);
+/// assert_eq!(natural_nearest(&list, "node10", 3), ["node9", "node10", "node11"]);
+/// assert_eq!(natural_nearest(&list, "node0", 2), ["node1", "node2"]);
+/// assert_eq!(natural_nearest(&list, "node18", 1), ["node20"]);
+/// ```
+pub fn natural_nearest<'a, T: AsRef<str>>(vals: &'a [T], query: &str, k: usize) -> &'a [T] {
+    let k = k.min(vals.len());
+    let index = vals.partition_point(|val| natural_cmp(val.as_ref(), query) == Ordering::Less);
+    let (mut start, mut end) = (index, index);
+
+    while end - start < k {
+        let left = start
+            .checked_sub(1)
+            .map(|i| distance(vals[i].as_ref(), query));
+        let right = vals.get(end).map(|val| distance(val.as_ref(), query));
+        match (left, right) {
+            (Some(left), Some(right)) if left <= right => start -= 1,
+            (Some(_), None) => start -= 1,
+            _ => end += 1,
+        }
+    }
+
+    &vals[start..end]
+}
+
+/// Returns how far `val` is from `query` at the first run where they differ,
+/// as whether that run is text and else the difference of the two numbers
+fn distance(val: &str, query: &str) -> (bool, u128) {
+    let mut first = None;
+    compare::walk_runs(val, query, &compare::Rules::default(), str::cmp, |step| {
+        if first.is_none() && step.ordering != Ordering::Equal {
+            first = Some(step);
+        }
+    });
+
+    match first {
+        None => (false, 0),
+        Some(step) if step.number && !step.left.is_empty() && !step.right.is_empty() => {
+            let left = segment::digits_value(step.left);
+            let right = segment::digits_value(step.right);
+            (false, left.abs_diff(right))
+        }
+        Some(_) => (true, 0),
+    }
+}
+
+#[test]
+fn test_natural_insert() {
+    let mut list: Vec<String> = vec![];
+
+    for name in [
+        "z10", "b23g", "z999", "x12z34", "x12z101", "z9", "z3", "z101", "z5",
+    ] {
+        natural_insert(&mut list, name.to_string());
+    }
+    assert_eq!(
+        list,
+        vec!["b23g", "x12z34", "x12z101", "z3", "z5", "z9", "z10", "z101", "z999"]
+    );
+
+    assert_eq!(natural_insertion_point(&list, "a"), 0);
+    assert_eq!(natural_insertion_point(&list, "z09"), 6);
+    assert_eq!(natural_insertion_point(&list, "zz"), list.len());
+    assert_eq!(natural_insert(&mut list, "z05".to_string()), 5);
+    assert_eq!(list[4..6], ["z5", "z05"]);
+}
+
+#[test]
+fn test_natural_range() {
+    use std::ops::Bound::{Excluded, Included};
+
+    let list = vec!["a", "z1", "z2", "z02", "z3", "z10"];
+    assert_eq!(natural_range(&list, "z2".."z3"), ["z2", "z02"]);
+    assert_eq!(natural_range(&list, "z2"..="z3"), ["z2", "z02", "z3"]);
+    assert_eq!(
+        natural_range(&list, (Excluded("z2"), Included("z10"))),
+        ["z3", "z10"]
+    );
+    assert_eq!(natural_range(&list, "z3"..), ["z3", "z10"]);
+    assert_eq!(natural_range(&list, ..), list.as_slice());
+    assert!(natural_range(&list, "z3".."z2").is_empty());
+    assert!(natural_range(&list, "zz"..).is_empty());
+}
+
+#[test]
+fn test_natural_nearest() {
+    let list = vec!["a1", "a2", "a3", "a10", "a20"];
+    assert_eq!(natural_nearest(&list, "a3", 1), ["a3"]);
+    assert_eq!(natural_nearest(&list, "a4", 1), ["a3"]);
+    assert_eq!(natural_nearest(&list, "a9", 1), ["a10"]);
+    assert_eq!(natural_nearest(&list, "a15", 2), ["a10", "a20"]);
+    assert_eq!(natural_nearest(&list, "a15", 1), ["a10"]);
+    assert_eq!(natural_nearest(&list, "a10", 3), ["a2", "a3", "a10"]);
+    assert_eq!(natural_nearest(&list, "a4", 3), ["a1", "a2", "a3"]);
+    assert_eq!(natural_nearest(&["a9", "b1"], "a10", 1), ["a9"]);
+    assert_eq!(natural_nearest(&["a", "a5"], "a1", 1), ["a5"]);
+    assert_eq!(natural_nearest(&list, "a99", 2), ["a10", "a20"]);
+    assert_eq!(natural_nearest(&list, "a٤", 1), ["a3"]);
+    assert_eq!(natural_nearest(&["a1", "a٣", "a10"], "a4", 1), ["a٣"]);
+    assert_eq!(natural_nearest(&list, "a2", 10), list.as_slice());
+    assert!(natural_nearest(&list, "a2", 0).is_empty());
+    assert!(natural_nearest::<&str>(&[], "a2", 3).is_empty());
+}
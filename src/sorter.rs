@@ -0,0 +1,237 @@
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use crate::NaturalOptions;
+
+/// Compares and sorts with a set of options, reusing its buffers between calls
+///
+/// Normalizing strings for [`NaturalOptions`] needs scratch space. A sorter
+/// keeps that space around, so a long running process doing many comparisons
+/// stops allocating once the buffers have grown. A sorter is `Send`, so each
+/// worker thread can own one.
+///
+/// # Examples
+/// ```
+/// use std::cmp::Ordering;
+/// use natural_sort::{NaturalOptions, NaturalSorter};
+/// let mut sorter = NaturalSorter::with_options(NaturalOptions::new().ignore_chars("_"));
+/// assert_eq!(sorter.compare("file_10", "file9"), Ordering::Greater);
+///
+/// let mut list = vec!["file_10", "file9", "file_1"];
+/// sorter.sort(&mut list);
+/// assert_eq!(list, vec!["file_1", "file9", "file_10"]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct NaturalSorter {
+    options: NaturalOptions,
+    left: String,
+    right: String,
+    arena: String,
+    keys: Vec<Range<usize>>,
+    order: Vec<usize>,
+}
+
+impl NaturalSorter {
+    /// Creates a sorter using the default options
+    pub fn new() -> Self {
+        NaturalSorter::default()
+    }
+
+    /// Creates a sorter using the given options
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - How to compare
+    pub fn with_options(options: NaturalOptions) -> Self {
+        NaturalSorter {
+            options,
+            ..NaturalSorter::default()
+        }
+    }
+
+    /// Returns the options this sorter compares with
+    pub fn options(&self) -> &NaturalOptions {
+        &self.options
+    }
+
+    /// Compares two string slices
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The left hand side
+    /// * `b` - The right hand side
+    pub fn compare(&mut self, a: &str, b: &str) -> Ordering {
+        self.options
+            .compare_with_buffers(a, b, &mut self.left, &mut self.right)
+    }
+
+    /// Sorts a slice
+    ///
+    /// Every value is normalized once into a shared arena. The sort is stable.
+    ///
+    /// # Arguments
+    ///
+    /// * `vals` - A slice of string-like values
+    pub fn sort<T: AsRef<str>>(&mut self, vals: &mut [T]) {
+        self.sort_order(vals);
+
+        // Follow each cycle of the permutation, leaving the identity behind
+        for start in 0..vals.len() {
+            let mut current = start;
+            while self.order[current] != start {
+                let next = self.order[current];
+                vals.swap(current, next);
+                self.order[current] = current;
+                current = next;
+            }
+            self.order[current] = current;
+        }
+    }
+
+    /// Returns the rank of every value in natural order, without reordering them
+    ///
+    /// Ranks start at 1, and values that compare equal share the rank of the
+    /// first of them, so the next distinct value skips ahead: `1, 2, 2, 4`.
+    ///
+    /// # Arguments
+    ///
+    /// * `vals` - A slice of string-like values
+    ///
+    /// # Examples
+    /// ```
+    /// use natural_sort::NaturalSorter;
+    /// let mut sorter = NaturalSorter::new();
+    /// assert_eq!(sorter.rank(&["b10", "b9", "a", "b09"]), vec![4, 2, 1, 2]);
+    /// ```
+    pub fn rank<T: AsRef<str>>(&mut self, vals: &[T]) -> Vec<usize> {
+        self.sort_order(vals);
+
+        let mut ranks = vec![0; vals.len()];
+        for (position, index) in self.order.iter().enumerate() {
+            ranks[*index] = match position {
+                0 => 1,
+                _ => {
+                    let previous = self.order[position - 1];
+                    match self.compare_keys(vals, previous, *index) {
+                        Ordering::Equal => ranks[previous],
+                        _ => position + 1,
+                    }
+                }
+            };
+        }
+
+        ranks
+    }
+
+    /// Normalizes every value into the arena and sorts their indices into `order`
+    fn sort_order<T: AsRef<str>>(&mut self, vals: &[T]) {
+        let levels = self.options.levels();
+
+        self.arena.clear();
+        self.keys.clear();
+        for val in vals.iter() {
+            for level in levels {
+                self.options.normalize(val.as_ref(), *level, &mut self.left);
+                let start = self.arena.len();
+                self.arena.push_str(&self.left);
+                self.keys.push(start..self.arena.len());
+            }
+        }
+
+        let mut order = std::mem::take(&mut self.order);
+        order.clear();
+        order.extend(0..vals.len());
+        order.sort_by(|&a, &b| self.compare_keys(vals, a, b));
+        self.order = order;
+    }
+
+    /// Compares the normalized keys of the values at indices `a` and `b`
+    fn compare_keys<T: AsRef<str>>(&self, vals: &[T], a: usize, b: usize) -> Ordering {
+        let levels = self.options.levels().len();
+        let key = |i: usize, level: usize| &self.arena[self.keys[i * levels + level].clone()];
+
+        (0..levels)
+            .map(|level| {
+                self.options
+                    .compare_normalized(key(a, level), key(b, level))
+            })
+            .find(|ord| *ord != Ordering::Equal)
+            .unwrap_or_else(|| {
+                let (a, b) = (vals[a].as_ref(), vals[b].as_ref());
+                self.options
+                    .compare_tails(a, b)
+                    .then_with(|| self.options.tiebreak(a, b))
+            })
+    }
+}
+
+/// Returns the rank of every value in natural order, without reordering them
+///
+/// Ranks start at 1 and values that compare equal share a rank, like a
+/// leaderboard: `1, 2, 2, 4`. See [`NaturalSorter::rank`] to rank with options.
+///
+/// # Arguments
+///
+/// * `vals` - A slice of string-like values
+///
+/// # Examples
+/// ```
+/// use natural_sort::natural_rank;
+/// let names = ["track10", "track2", "track02", "intro"];
+/// assert_eq!(natural_rank(&names), vec![4, 2, 2, 1]);
+/// ```
+pub fn natural_rank<T: AsRef<str>>(vals: &[T]) -> Vec<usize> {
+    NaturalSorter::new().rank(vals)
+}
+
+#[test]
+fn test_natural_sorter() {
+    fn assert_send<T: Send>() {}
+    assert_send::<NaturalSorter>();
+
+    let mut sorter = NaturalSorter::new();
+    let mut list = vec![
+        "z10a", "b23g", "z999", "z10", "x12z34", "x12z101", "z9", "z3", "z101", "z5",
+    ];
+    let expected = vec![
+        "b23g", "x12z34", "x12z101", "z3", "z5", "z9", "z10", "z10a", "z101", "z999",
+    ];
+
+    sorter.sort(&mut list);
+    assert_eq!(list, expected);
+
+    list.reverse();
+    sorter.sort(&mut list);
+    assert_eq!(list, expected);
+
+    #[cfg(feature = "unicode")]
+    {
+        let mut sorter =
+            NaturalSorter::with_options(NaturalOptions::new().strength(crate::Strength::Tertiary));
+        let mut list = vec!["file10", "File2", "fíle1", "FILE1", "file1"];
+        sorter.sort(&mut list);
+        assert_eq!(list, vec!["FILE1", "file1", "fíle1", "File2", "file10"]);
+        assert_eq!(sorter.compare("FILE1", "file1"), Ordering::Less);
+    }
+}
+
+#[test]
+fn test_natural_rank() {
+    let empty: [&str; 0] = [];
+    assert_eq!(natural_rank(&empty), Vec::<usize>::new());
+    assert_eq!(natural_rank(&["x"]), vec![1]);
+    assert_eq!(natural_rank(&["a1", "a01", "a001"]), vec![1, 1, 1]);
+    assert_eq!(
+        natural_rank(&["z10", "z9", "z09", "a", "z10", "b"]),
+        vec![5, 3, 3, 1, 5, 2]
+    );
+
+    #[cfg(feature = "unicode")]
+    {
+        let names = vec![String::from("b2"), String::from("B1")];
+        let mut sorter =
+            NaturalSorter::with_options(NaturalOptions::new().strength(crate::Strength::Primary));
+        assert_eq!(sorter.rank(&names), vec![2, 1]);
+        assert_eq!(sorter.rank(&["File", "file", "FILE"]), vec![1, 1, 1]);
+    }
+}
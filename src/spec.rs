@@ -0,0 +1,185 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::NaturalError;
+
+/// The ordering spec of [`natural_cmp`](crate::natural_cmp), as shipped in `spec/ordering.tsv`
+///
+/// Each line holds two strings and their expected relation, separated by tabs.
+/// A change to any existing line is a breaking change of the crate.
+pub const ORDERING_SPEC: &str = include_str!("../spec/ordering.tsv");
+
+/// A pair of strings a comparison ordered differently than a spec expects
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpecViolation {
+    /// The line of the spec, counting from 1
+    pub line: usize,
+    /// The left hand side as passed to the comparison
+    pub left: String,
+    /// The right hand side as passed to the comparison
+    pub right: String,
+    /// The ordering the spec expects
+    pub expected: Ordering,
+    /// The ordering the comparison returned
+    pub actual: Ordering,
+}
+
+impl fmt::Display for SpecViolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}: {:?} {} {:?} expected, got {}",
+            self.line,
+            self.left,
+            relation(self.expected),
+            self.right,
+            relation(self.actual)
+        )
+    }
+}
+
+fn relation(ord: Ordering) -> &'static str {
+    match ord {
+        Ordering::Less => "<",
+        Ordering::Equal => "=",
+        Ordering::Greater => ">",
+    }
+}
+
+/// Checks a comparison against an ordering spec and returns every pair it gets wrong
+///
+/// Every pair is compared both ways round, so a comparison that isn't
+/// antisymmetric is caught as well. Run it on [`ORDERING_SPEC`] with the
+/// comparison a product relies on, or on a spec of your own in the same
+/// format, to find out about ordering changes before a crate upgrade ships.
+///
+/// # Arguments
+///
+/// * `spec` - The spec, in the format of `spec/ordering.tsv`
+/// * `cmp` - The comparison to check
+///
+/// # Errors
+///
+/// Returns [`NaturalError::MalformedSpec`] for a line that isn't a comment and
+/// doesn't hold two strings and a relation.
+///
+/// # Examples
+/// ```
+/// use natural_sort::{natural_cmp, natural_cmp_ci, verify_spec, ORDERING_SPEC};
+/// assert!(verify_spec(ORDERING_SPEC, natural_cmp).unwrap().is_empty());
+///
+/// let mine = "img10\t>\timg9\nIMG1\t=\timg1\n";
+/// assert!(verify_spec(mine, natural_cmp_ci).unwrap().is_empty());
+/// assert_eq!(verify_spec(mine, natural_cmp).unwrap().len(), 2);
+/// ```
+pub fn verify_spec<F>(spec: &str, mut cmp: F) -> Result<Vec<SpecViolation>, NaturalError>
+where
+    F: FnMut(&str, &str) -> Ordering,
+{
+    let mut violations = vec![];
+
+    for (index, line) in spec.lines().enumerate() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let malformed = NaturalError::MalformedSpec { line: index + 1 };
+
+        let fields: Vec<&str> = line.split('\t').collect();
+        let [left, relation, right] = fields[..] else {
+            return Err(malformed);
+        };
+        let expected = match relation {
+            "<" => Ordering::Less,
+            "=" => Ordering::Equal,
+            ">" => Ordering::Greater,
+            _ => return Err(malformed),
+        };
+        let left = unescape(left).ok_or(malformed.clone())?;
+        let right = unescape(right).ok_or(malformed)?;
+
+        for (left, right, expected) in [
+            (&left, &right, expected),
+            (&right, &left, expected.reverse()),
+        ] {
+            let actual = cmp(left, right);
+            if actual != expected {
+                violations.push(SpecViolation {
+                    line: index + 1,
+                    left: left.clone(),
+                    right: right.clone(),
+                    expected,
+                    actual,
+                });
+            }
+        }
+    }
+
+    Ok(violations)
+}
+
+/// Resolves the `\t`, `\n`, `\\` and `\u{...}` escapes of a spec field
+fn unescape(field: &str) -> Option<String> {
+    let mut out = String::with_capacity(field.len());
+    let mut chars = field.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next()? {
+            't' => out.push('\t'),
+            'n' => out.push('\n'),
+            '\\' => out.push('\\'),
+            'u' => {
+                let rest = chars.as_str().strip_prefix('{')?;
+                let (hex, tail) = rest.split_once('}')?;
+                out.push(char::from_u32(u32::from_str_radix(hex, 16).ok()?)?);
+                chars = tail.chars();
+            }
+            _ => return None,
+        }
+    }
+
+    Some(out)
+}
+
+#[test]
+fn test_verify_spec() {
+    fn assert_spec(name: &str, cmp: impl Fn(&str, &str) -> Ordering) {
+        let violations = verify_spec(ORDERING_SPEC, cmp).unwrap();
+        assert!(violations.is_empty(), "{}: {:?}", name, violations);
+    }
+
+    assert_spec("natural_cmp", crate::natural_cmp);
+    assert_spec("options", |a, b| crate::NaturalOptions::new().compare(a, b));
+    assert_spec("chars", |a, b| {
+        crate::natural_cmp_chars(a.chars(), b.chars())
+    });
+    assert_spec("natural", |a, b| crate::Natural(a).cmp(&crate::Natural(b)));
+    assert_spec("cache", |a, b| crate::NaturalKeyCache::new(4).compare(a, b));
+
+    let result = verify_spec("# comment\n\na\\u{301}\t<\tb\n\tb", |_, _| Ordering::Equal);
+    assert_eq!(result, Err(NaturalError::MalformedSpec { line: 4 }));
+
+    let violations = verify_spec("a2\t<\ta10\n", str::cmp).unwrap();
+    assert_eq!(violations.len(), 2);
+    assert_eq!(
+        violations[1].to_string(),
+        "line 1: \"a10\" > \"a2\" expected, got <"
+    );
+
+    for bad in [
+        "a\t<",
+        "a\t?\tb",
+        "a\\x\t<\tb",
+        "a\\u{110000}\t=\ta",
+        "\\u{41\t=\ta",
+    ] {
+        assert_eq!(
+            verify_spec(bad, str::cmp),
+            Err(NaturalError::MalformedSpec { line: 1 })
+        );
+    }
+    assert_eq!(unescape("\\u{41}\\t\\\\"), Some(String::from("A\t\\")));
+}
@@ -0,0 +1,127 @@
+use crate::{numeric, scan, NaturalError};
+
+/// Marks a number, and with a second byte an escaped text character
+const MARK: char = '\u{1}';
+
+/// Builds a text sort key that orders naturally under plain string comparison
+///
+/// Every number is written as `'\u{1}'` followed by its digits in ASCII, zero padded to
+/// `width`, so `"img7.png"` becomes `"img\u{1}\u{1}0007.png"` for a width of 4.
+/// The marker sorts before any text, the same way [`natural_cmp`](crate::natural_cmp)
+/// puts a number before text it meets, so `"a1"` sorts before `"a-"` by key as well.
+/// The control characters `'\u{0}'` and `'\u{1}'` in text are escaped and the
+/// key never contains a NUL.
+/// Store the key in a shadow column and `ORDER BY` it to get natural order
+/// from a database that can't run custom comparisons.
+///
+/// The key only matches `natural_cmp` under a binary collation such as
+/// `COLLATE "C"` in PostgreSQL or `BINARY` in SQLite and MySQL. Locale aware
+/// collations may ignore punctuation, case and control characters.
+///
+/// # Arguments
+///
+/// * `s` - The string to build the key for
+/// * `width` - The number of digits to pad every number to
+///
+/// # Errors
+///
+/// Returns [`NaturalError::NumberTooWide`] if a number has more than `width`
+/// significant digits, since it would no longer sort by value. Pick a width
+/// that fits the longest number you expect.
+///
+/// # Examples
+/// ```
+/// use natural_sort::natural_sql_key;
+/// assert_eq!(natural_sql_key("img7.png", 4).unwrap(), "img\u{1}\u{1}0007.png");
+/// assert_eq!(natural_sql_key("id007", 3), natural_sql_key("id7", 3));
+/// assert!(natural_sql_key("a1", 3).unwrap() < natural_sql_key("a-", 3).unwrap());
+/// assert!(natural_sql_key("img1234.png", 3).is_err());
+/// ```
+pub fn natural_sql_key(s: &str, width: usize) -> Result<String, NaturalError> {
+    let mut out = String::with_capacity(s.len() + width + 2);
+    let mut rest = s;
+
+    while !rest.is_empty() {
+        let (text, tail) = rest.split_at(scan::find_numeric(rest).unwrap_or(rest.len()));
+        for c in text.chars() {
+            match c {
+                '\u{0}' => out.extend([MARK, '\u{2}']),
+                MARK => out.extend([MARK, '\u{3}']),
+                _ => out.push(c),
+            }
+        }
+
+        let (num, tail) = tail.split_at(scan::find_non_numeric(tail).unwrap_or(tail.len()));
+        if !num.is_empty() {
+            let digits = numeric::trim_zeros(num);
+            let count = digits.chars().count();
+            if count > width {
+                return Err(NaturalError::NumberTooWide {
+                    digits: count,
+                    width,
+                });
+            }
+            out.extend([MARK, MARK]);
+            out.extend(std::iter::repeat_n('0', width - count));
+            numeric::push_ascii_digits(&mut out, digits);
+        }
+
+        rest = tail;
+    }
+
+    Ok(out)
+}
+
+#[test]
+fn test_natural_sql_key() {
+    let mut list = vec![
+        "z10a", "b23g", "z999", "z10", "x12z34", "x12z101", "z9", "z3", "z101", "z5",
+    ];
+    list.sort_by_key(|s| natural_sql_key(s, 3).unwrap());
+    assert_eq!(
+        list,
+        vec!["b23g", "x12z34", "x12z101", "z3", "z5", "z9", "z10", "z10a", "z101", "z999"]
+    );
+
+    assert_eq!(natural_sql_key("", 3).unwrap(), "");
+    assert_eq!(natural_sql_key("0", 3).unwrap(), "\u{1}\u{1}000");
+    assert_eq!(natural_sql_key("a\u{0}", 3).unwrap(), "a\u{1}\u{2}");
+    assert_eq!(
+        natural_sql_key("12345", 3),
+        Err(NaturalError::NumberTooWide {
+            digits: 5,
+            width: 3
+        })
+    );
+    assert_eq!(natural_sql_key("a0b", 0).unwrap(), "a\u{1}\u{1}b");
+    assert!(natural_sql_key("a1b", 0).is_err());
+}
+
+#[test]
+fn test_natural_sql_key_order() {
+    use crate::natural_cmp;
+    use std::cmp::Ordering;
+
+    let corpus = [
+        "", "0", "00", "1", "01", "9", "10", "a", "a-", "a.", "a 1", "a1", "a01", "a1-", "a1b",
+        "a2", "a10", "a-1", "a\u{0}", "a\u{1}", "a\u{1}1", "a\u{2}", "a\u{0}1", "z10a", "x12z34",
+        "x12z101", "a١", "a٢", "a١٠", "a12", "a٠٣", "a3", "a٣", "é2", "é10", "ab", "a\u{7f}",
+        "\u{1}", "~",
+    ];
+
+    for a in corpus {
+        for b in corpus {
+            let key_a = natural_sql_key(a, 4).unwrap();
+            let key_b = natural_sql_key(b, 4).unwrap();
+            assert!(!key_a.contains('\u{0}'), "{:?}", a);
+            assert_eq!(
+                key_a < key_b,
+                natural_cmp(a, b) == Ordering::Less,
+                "{:?} {:?}",
+                a,
+                b
+            );
+            assert_eq!(key_a == key_b, natural_cmp(a, b) == Ordering::Equal);
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! Sorts keys arriving from async code, enabled with the `tokio` feature
+
+use std::future::poll_fn;
+use std::io;
+use std::mem;
+use std::path::PathBuf;
+use std::pin::{pin, Pin};
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use tokio::sync::mpsc;
+use tokio::task;
+
+use crate::external::{Merge, Run};
+
+/// How many keys the merge reads ahead of the consumer
+const READ_AHEAD: usize = 1024;
+
+/// Collects keys from async code and yields them in natural order, within a memory limit
+///
+/// Whenever the buffered keys take up more than the memory limit they are
+/// sorted and written to a run file in the spill directory. At the end the runs
+/// are merged, so the peak memory use is the limit plus a small buffer per run.
+/// Sorting, writing and merging happen on Tokio's blocking threads. The sort is
+/// stable and run files are removed once the sorted stream is dropped.
+///
+/// # Examples
+/// ```
+/// use natural_sort::NaturalSortStream;
+/// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// runtime.block_on(async {
+///     let mut sorter = NaturalSortStream::new().memory_limit(64);
+///     for page in [["logs/day10", "logs/day2"], ["logs/day1", "logs/day9"]] {
+///         for key in page {
+///             sorter.push(key.to_string()).await.unwrap();
+///         }
+///     }
+///
+///     let mut sorted = sorter.finish().await.unwrap();
+///     let mut keys = vec![];
+///     while let Some(key) = sorted.next().await {
+///         keys.push(key.unwrap());
+///     }
+///     assert_eq!(keys, vec!["logs/day1", "logs/day2", "logs/day9", "logs/day10"]);
+/// });
+/// ```
+#[derive(Debug)]
+pub struct NaturalSortStream {
+    limit: usize,
+    dir: PathBuf,
+    buffer: Vec<String>,
+    buffered: usize,
+    runs: Vec<Run>,
+}
+
+impl Default for NaturalSortStream {
+    fn default() -> Self {
+        NaturalSortStream {
+            limit: 64 << 20,
+            dir: std::env::temp_dir(),
+            buffer: vec![],
+            buffered: 0,
+            runs: vec![],
+        }
+    }
+}
+
+impl NaturalSortStream {
+    /// Creates a sorter that buffers up to 64 MiB and spills to the system's temporary directory
+    pub fn new() -> Self {
+        NaturalSortStream::default()
+    }
+
+    /// Sets how many bytes of keys are buffered before they are spilled to disk
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The memory limit, counting each key's text and its `String`
+    pub fn memory_limit(mut self, bytes: usize) -> Self {
+        self.limit = bytes;
+        self
+    }
+
+    /// Sets the directory run files are written to
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - An existing, writable directory
+    pub fn spill_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dir = dir.into();
+        self
+    }
+
+    /// Adds a key, spilling the buffered keys to disk if they reached the memory limit
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to sort
+    pub async fn push(&mut self, key: String) -> io::Result<()> {
+        self.buffered += key.len() + mem::size_of::<String>();
+        self.buffer.push(key);
+
+        if self.buffered >= self.limit {
+            let mut keys = mem::take(&mut self.buffer);
+            let dir = self.dir.clone();
+            let run = task::spawn_blocking(move || Run::write(&dir, &mut keys))
+                .await
+                .map_err(io::Error::other)??;
+            self.runs.push(run);
+            self.buffered = 0;
+        }
+
+        Ok(())
+    }
+
+    /// Adds every key of `input` and returns them in natural order
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - A stream of keys, like the pages of a listing flattened
+    pub async fn sort<S: Stream<Item = String>>(mut self, input: S) -> io::Result<SortedStream> {
+        let mut input = pin!(input);
+        while let Some(key) = poll_fn(|cx| input.as_mut().poll_next(cx)).await {
+            self.push(key).await?;
+        }
+        self.finish().await
+    }
+
+    /// Stops taking keys and returns all of them in natural order
+    pub async fn finish(self) -> io::Result<SortedStream> {
+        let NaturalSortStream { buffer, runs, .. } = self;
+        let merge = task::spawn_blocking(move || Merge::new(runs, buffer))
+            .await
+            .map_err(io::Error::other)??;
+
+        let (sender, receiver) = mpsc::channel(READ_AHEAD);
+        task::spawn_blocking(move || {
+            for key in merge {
+                let failed = key.is_err();
+                if sender.blocking_send(key).is_err() || failed {
+                    break;
+                }
+            }
+        });
+
+        Ok(SortedStream { receiver })
+    }
+}
+
+/// The keys of a [`NaturalSortStream`] in natural order
+///
+/// Yields an error and ends if a run file can't be read back.
+#[derive(Debug)]
+pub struct SortedStream {
+    receiver: mpsc::Receiver<io::Result<String>>,
+}
+
+impl SortedStream {
+    /// Returns the next key, or `None` after the last one
+    pub async fn next(&mut self) -> Option<io::Result<String>> {
+        self.receiver.recv().await
+    }
+}
+
+impl Stream for SortedStream {
+    type Item = io::Result<String>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+#[test]
+fn test_natural_sort_stream() {
+    /// Yields the keys of a vector, one per poll
+    struct Keys(std::vec::IntoIter<String>);
+
+    impl Stream for Keys {
+        type Item = String;
+
+        fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<String>> {
+            Poll::Ready(self.0.next())
+        }
+    }
+
+    let input: Vec<String> = (0..500)
+        .map(|i| format!("obj/{}/part{:0w$}", (i * 37) % 7, i % 50, w = i % 3))
+        .collect();
+    let mut expected = input.clone();
+    expected.sort_by(|a, b| crate::natural_cmp(a, b));
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        let sorter = NaturalSortStream::new().memory_limit(1000);
+        let mut sorted = sorter.sort(Keys(input.into_iter())).await.unwrap();
+        let mut keys = vec![];
+        while let Some(key) = poll_fn(|cx| Pin::new(&mut sorted).poll_next(cx)).await {
+            keys.push(key.unwrap());
+        }
+        assert_eq!(keys, expected);
+
+        let sorted = NaturalSortStream::new().finish().await.unwrap();
+        assert!(sorted.receiver.is_empty());
+    });
+}
@@ -0,0 +1,124 @@
+//! A port of glibc's `strverscmp`
+
+use std::cmp::Ordering;
+
+// States of the scanner, each followed by one slot per class of the current byte
+const S_N: usize = 0;
+const S_I: usize = 3;
+const S_F: usize = 6;
+const S_Z: usize = 9;
+
+// Outcomes beyond a fixed ordering
+const CMP: i8 = 2;
+const LEN: i8 = 3;
+
+#[rustfmt::skip]
+const NEXT_STATE: [usize; 12] = [
+    // x    d    0
+    S_N, S_I, S_Z, // S_N: normal
+    S_N, S_I, S_I, // S_I: comparing integral part
+    S_N, S_F, S_F, // S_F: comparing fractional parts
+    S_N, S_F, S_Z, // S_Z: idem but with leading zeros only
+];
+
+#[rustfmt::skip]
+const RESULT_TYPE: [i8; 36] = [
+    // x/x  x/d  x/0  d/x  d/d  d/0  0/x  0/d  0/0
+    CMP, CMP, CMP, CMP, LEN, CMP, CMP, CMP, CMP, // S_N
+    CMP, -1,  -1,  1,   LEN, LEN, 1,   LEN, LEN, // S_I
+    CMP, CMP, CMP, CMP, CMP, CMP, CMP, CMP, CMP, // S_F
+    CMP, 1,   1,   -1,  CMP, CMP, -1,  CMP, CMP, // S_Z
+];
+
+/// Sorts a byte into other (0), non-zero digit (1) or zero (2)
+fn class(c: u8) -> usize {
+    usize::from(c == b'0') + usize::from(c.is_ascii_digit())
+}
+
+/// Compares like glibc's `strverscmp`, including stopping at the first NUL byte
+pub(crate) fn strverscmp(a: &str, b: &str) -> Ordering {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let at = |s: &[u8], i: usize| s.get(i).copied().unwrap_or(0);
+
+    let mut i = 0;
+    let (mut c1, mut c2) = (at(a, 0), at(b, 0));
+    let mut state = S_N + class(c1);
+
+    while c1 == c2 {
+        if c1 == 0 {
+            return Ordering::Equal;
+        }
+        state = NEXT_STATE[state];
+        i += 1;
+        c1 = at(a, i);
+        c2 = at(b, i);
+        state += class(c1);
+    }
+
+    let diff = c1.cmp(&c2);
+    match RESULT_TYPE[state * 3 + class(c2)] {
+        CMP => diff,
+        LEN => {
+            let mut j = i + 1;
+            while at(a, j).is_ascii_digit() {
+                if !at(b, j).is_ascii_digit() {
+                    return Ordering::Greater;
+                }
+                j += 1;
+            }
+            match at(b, j).is_ascii_digit() {
+                true => Ordering::Less,
+                false => diff,
+            }
+        }
+        fixed => fixed.cmp(&0),
+    }
+}
+
+#[test]
+fn test_strverscmp() {
+    let chain = ["000", "00", "01", "010", "09", "0", "1", "9", "10"];
+    for (i, a) in chain.iter().enumerate() {
+        for (j, b) in chain.iter().enumerate() {
+            assert_eq!(strverscmp(a, b), i.cmp(&j), "{} {}", a, b);
+        }
+    }
+
+    assert_eq!(strverscmp("1.01", "1.2"), Ordering::Less);
+    assert_eq!(strverscmp("item#99", "item#100"), Ordering::Less);
+    assert_eq!(strverscmp("alpha1", "alpha001"), Ordering::Greater);
+    assert_eq!(strverscmp("part1_f012", "part1_f01"), Ordering::Greater);
+    assert_eq!(strverscmp("foo.bar", "foo.bar"), Ordering::Equal);
+    assert_eq!(strverscmp("a\0b", "a\0c"), Ordering::Equal);
+}
+
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+#[test]
+fn test_strverscmp_matches_glibc() {
+    use std::ffi::{c_char, c_int, CString};
+
+    extern "C" {
+        #[link_name = "strverscmp"]
+        fn glibc_strverscmp(a: *const c_char, b: *const c_char) -> c_int;
+    }
+
+    let mut strings = vec![String::new()];
+    for _ in 0..4 {
+        let longer: Vec<String> = strings
+            .iter()
+            .flat_map(|s| ["0", "1", "9", "a", "."].map(|c| format!("{}{}", s, c)))
+            .collect();
+        strings.extend(longer);
+    }
+    strings.sort();
+    strings.dedup();
+
+    for a in &strings {
+        let ca = CString::new(a.as_str()).unwrap();
+        for b in &strings {
+            let cb = CString::new(b.as_str()).unwrap();
+            let expected = unsafe { glibc_strverscmp(ca.as_ptr(), cb.as_ptr()) }.cmp(&0);
+            assert_eq!(strverscmp(a, b), expected, "{:?} {:?}", a, b);
+        }
+    }
+}
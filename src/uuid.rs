@@ -0,0 +1,82 @@
+//! Recognizes UUID-shaped substrings like `7f3a9c2e-1b4d-4e8f-9a0b-3c5d7e9f1a2b`
+
+/// The length of each dash separated group of a UUID
+const GROUPS: [usize; 5] = [8, 4, 4, 4, 12];
+
+/// What every UUID is replaced with, U+FFFC OBJECT REPLACEMENT CHARACTER
+pub(crate) const TOKEN: char = '\u{fffc}';
+
+/// Returns the length of a UUID at the start of `s`, in any case
+fn uuid_len(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+    let mut end = 0;
+
+    for (i, len) in GROUPS.iter().enumerate() {
+        if i > 0 {
+            if bytes.get(end) != Some(&b'-') {
+                return None;
+            }
+            end += 1;
+        }
+        let group = bytes.get(end..end + len)?;
+        if !group.iter().all(u8::is_ascii_hexdigit) {
+            return None;
+        }
+        end += len;
+    }
+
+    match s[end..].starts_with(char::is_alphanumeric) {
+        true => None,
+        false => Some(end),
+    }
+}
+
+/// Replaces every UUID with [`TOKEN`]
+///
+/// `"job-7f3a9c2e-1b4d-4e8f-9a0b-3c5d7e9f1a2b-12"` becomes `"job-\u{fffc}-12"`,
+/// so the UUID neither splits into numbers nor decides the order. A UUID must
+/// not touch other letters or digits.
+pub(crate) fn mask_uuids(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    let mut after_alphanumeric = false;
+
+    while let Some(c) = rest.chars().next() {
+        if !after_alphanumeric {
+            if let Some(len) = uuid_len(rest) {
+                out.push(TOKEN);
+                rest = &rest[len..];
+                after_alphanumeric = true;
+                continue;
+            }
+        }
+
+        out.push(c);
+        after_alphanumeric = c.is_alphanumeric();
+        rest = &rest[c.len_utf8()..];
+    }
+
+    out
+}
+
+#[test]
+fn test_mask_uuids() {
+    let uuid = "7f3a9c2e-1b4d-4e8f-9a0b-3c5d7e9f1a2b";
+    assert_eq!(mask_uuids(uuid), "\u{fffc}");
+    assert_eq!(mask_uuids(&format!("job-{}-12", uuid)), "job-\u{fffc}-12");
+    assert_eq!(
+        mask_uuids(&format!("{}_{}", uuid.to_uppercase(), uuid)),
+        "\u{fffc}_\u{fffc}"
+    );
+    assert_eq!(mask_uuids(&format!("x{}", uuid)), format!("x{}", uuid));
+    assert_eq!(mask_uuids(&format!("{}0", uuid)), format!("{}0", uuid));
+    assert_eq!(mask_uuids(&uuid[1..]), &uuid[1..]);
+    assert_eq!(
+        mask_uuids("7f3a9c2e-1b4d-4e8f-9a0b"),
+        "7f3a9c2e-1b4d-4e8f-9a0b"
+    );
+    assert_eq!(mask_uuids("ä-7f3a9c2e"), "ä-7f3a9c2e");
+    assert_eq!(mask_uuids(&format!("ä{}", uuid)), format!("ä{}", uuid));
+    assert_eq!(mask_uuids(&format!("{}ä", uuid)), format!("{}ä", uuid));
+    assert_eq!(mask_uuids(&format!("ä-{}", uuid)), "ä-\u{fffc}");
+}
@@ -0,0 +1,34 @@
+//! JavaScript bindings, enabled with the `wasm-bindgen` feature
+
+use wasm_bindgen::prelude::*;
+
+use crate::natural_cmp;
+
+/// Compares two strings in a natural way
+///
+/// Exported as `naturalCompare`. Returns `-1`, `0` or `1`, so it can be passed
+/// straight to `Array.prototype.sort`.
+#[wasm_bindgen(js_name = naturalCompare)]
+pub fn natural_compare(a: &str, b: &str) -> i32 {
+    natural_cmp(a, b) as i32
+}
+
+/// Returns a naturally sorted copy of an array of strings
+///
+/// Exported as `naturalSort`.
+#[wasm_bindgen(js_name = naturalSort)]
+pub fn natural_sort(vals: Vec<String>) -> Vec<String> {
+    let mut vals = vals;
+    vals.sort_by(|a, b| natural_cmp(a, b));
+    vals
+}
+
+#[test]
+fn test_js_bindings() {
+    assert_eq!(natural_compare("z9", "z10"), -1);
+    assert_eq!(natural_compare("z10", "z10"), 0);
+    assert_eq!(natural_compare("z10", "z9"), 1);
+
+    let vals = vec!["z10".to_string(), "z9".to_string(), "b23g".to_string()];
+    assert_eq!(natural_sort(vals), vec!["b23g", "z9", "z10"]);
+}
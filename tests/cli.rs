@@ -0,0 +1,98 @@
+//! Runs the `natural-sort` binary end to end
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+fn natural_sort(args: &[&str], stdin: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_natural-sort"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("natural-sort-{}-{}", std::process::id(), name))
+}
+
+#[test]
+fn test_sorts_stdin() {
+    let out = natural_sort(&[], b"z10\nz9\nz101\nz3");
+    assert_eq!(out.status.code(), Some(0));
+    assert_eq!(out.stdout, b"z3\nz9\nz10\nz101\n");
+}
+
+#[test]
+fn test_zero_terminated() {
+    let out = natural_sort(&["-z"], b"b\n10\0b\n9\0a\0");
+    assert_eq!(out.status.code(), Some(0));
+    assert_eq!(out.stdout, b"a\0b\n9\0b\n10\0");
+}
+
+#[test]
+fn test_files_and_output() {
+    let first = temp_path("first");
+    let second = temp_path("second");
+    let output = temp_path("output");
+    std::fs::write(&first, "img10\nimg2").unwrap();
+    std::fs::write(&second, "img1\n").unwrap();
+
+    let out = natural_sort(
+        &[
+            "-o",
+            output.to_str().unwrap(),
+            first.to_str().unwrap(),
+            "-",
+            second.to_str().unwrap(),
+        ],
+        b"img3\n",
+    );
+    assert_eq!(out.status.code(), Some(0));
+    assert!(out.stdout.is_empty());
+    assert_eq!(
+        std::fs::read_to_string(&output).unwrap(),
+        "img1\nimg2\nimg3\nimg10\n"
+    );
+
+    for path in [first, second, output] {
+        std::fs::remove_file(path).unwrap();
+    }
+}
+
+#[test]
+fn test_rename() {
+    let out = natural_sort(&["--rename", "3"], b"p/img10\np/img7\np/img007x\n");
+    assert_eq!(out.status.code(), Some(0));
+    assert_eq!(out.stdout, b"p/img7 -> p/img007\np/img10 -> p/img010\n");
+
+    let out = natural_sort(&["--rename", "3"], b"img7\nimg07\n");
+    assert_eq!(out.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(out.stderr).unwrap(),
+        "natural-sort: img7 and img07 would both become img007\n"
+    );
+}
+
+#[test]
+fn test_exit_codes() {
+    let out = natural_sort(&["--help"], b"");
+    assert_eq!(out.status.code(), Some(0));
+    assert!(out.stdout.starts_with(b"Usage: natural-sort"));
+
+    let out = natural_sort(&["--bogus"], b"");
+    assert_eq!(out.status.code(), Some(2));
+    assert!(out.stdout.is_empty());
+    assert!(out
+        .stderr
+        .starts_with(b"natural-sort: unknown option --bogus\n"));
+
+    let missing = temp_path("missing");
+    let out = natural_sort(&[missing.to_str().unwrap()], b"");
+    assert_eq!(out.status.code(), Some(1));
+    assert!(out.stderr.starts_with(b"natural-sort: "));
+}